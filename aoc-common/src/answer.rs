@@ -0,0 +1,160 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Answer to one part of a puzzle.
+///
+/// Every day returns its answers as this type, whatever it computes them with,
+/// so they print, compare and serialize the same way everywhere. The variant
+/// follows from the value: an answer written as `"42"` is the same as `42`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An integer that fits in an `i128`.
+    Int(i128),
+    /// A larger integer, as its decimal digits after an optional `-`.
+    BigInt(String),
+    /// Anything that is not an integer, like a code or a list of letters.
+    Text(String),
+}
+
+impl Answer {
+    /// Reads an answer from its printed form, e.g. from a file of known answers.
+    pub fn parse(s: &str) -> Answer {
+        let s = s.trim();
+        // Only integers written the way they print, so `007` or `+5` stay text.
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        let canonical = !digits.is_empty()
+            && digits.bytes().all(|b| b.is_ascii_digit())
+            && (!digits.starts_with('0') || s == "0");
+        match s.parse::<i128>() {
+            Ok(n) if canonical => Answer::Int(n),
+            _ if canonical => Answer::BigInt(s.to_string()),
+            _ => Answer::Text(s.to_string()),
+        }
+    }
+
+    /// The integer value, if the answer is an integer of at most 128 bits.
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(*n),
+            Answer::BigInt(_) | Answer::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(digits) => write!(f, "{}", digits),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Answer, Infallible> {
+        Ok(Answer::parse(s))
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    match i128::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => Answer::BigInt(n.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::parse(s)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::parse(&s)
+    }
+}
+
+/// Integers that fit in an `i64` serialize as numbers, everything else as strings,
+/// so the answers can be written to TOML and JSON alike.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.as_int().map(i64::try_from) {
+            Some(Ok(n)) => serializer.serialize_i64(n),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Answer, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Int(i64),
+            UInt(u64),
+            Text(String),
+        }
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Int(n) => Answer::from(n),
+            Raw::UInt(n) => Answer::from(n),
+            Raw::Text(s) => Answer::parse(&s),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Answer::from(-3i32), Answer::Int(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::Int(u64::MAX as i128));
+        assert_eq!(Answer::from(u128::MAX), Answer::BigInt(u128::MAX.to_string()));
+        assert_eq!(Answer::from(u128::MAX).to_string(), "340282366920938463463374607431768211455");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Answer::parse(" 344323629240733\n"), Answer::Int(344323629240733));
+        assert_eq!(Answer::from("42"), Answer::from(42u8));
+        assert_eq!(Answer::parse("-1234567890123456789012345678901234567890"), Answer::BigInt("-1234567890123456789012345678901234567890".to_string()));
+        assert_eq!(Answer::parse("007"), Answer::Text("007".to_string()));
+        assert_eq!(Answer::parse("6,4,0"), Answer::Text("6,4,0".to_string()));
+        assert_eq!(Answer::parse("-"), Answer::Text("-".to_string()));
+        assert_eq!(Answer::parse("0"), Answer::Int(0));
+        assert_eq!(Answer::parse("+5"), Answer::Text("+5".to_string()));
+    }
+
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Answers {
+            small: Answer,
+            large: Answer,
+            text: Answer,
+        }
+        let answers = Answers { small: Answer::Int(17095), large: Answer::from(u128::MAX), text: Answer::from("abc") };
+        let toml = toml::to_string(&answers).unwrap();
+        assert_eq!(toml, "small = 17095\nlarge = \"340282366920938463463374607431768211455\"\ntext = \"abc\"\n");
+        assert_eq!(toml::from_str::<Answers>(&toml).unwrap(), answers);
+        assert_eq!(toml::from_str::<Answers>("small = \"5\"\nlarge = -2\ntext = \"x\"\n").unwrap().small, Answer::Int(5));
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::{Answer, Config, InputSource, Params, Part, Solution, solve};
+
+/// Answers known to be right, kept in each day's `answers.toml`:
+///
+/// ```toml
+/// [example]
+/// part1 = 357
+/// part2 = 3121910778619
+///
+/// [input]
+/// part1 = 17095
+/// ```
+///
+/// Answers may be written as integers or strings; parts without a known answer are left out.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnownAnswers {
+    /// Answers for the example input (`test-part1.txt` / `test-part2.txt`).
+    #[serde(default)]
+    pub example: PartAnswers,
+    /// Answers for the real puzzle input (`input.txt`).
+    #[serde(default)]
+    pub input: PartAnswers,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartAnswers {
+    #[serde(default)]
+    pub part1: Option<Answer>,
+    #[serde(default)]
+    pub part2: Option<Answer>,
+}
+
+impl PartAnswers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    /// The parts that have a known answer.
+    pub fn parts(&self) -> Vec<Part> {
+        Part::ALL.into_iter().filter(|&part| self.get(part).is_some()).collect()
+    }
+}
+
+impl KnownAnswers {
+    pub fn parse(s: &str) -> Result<KnownAnswers, String> {
+        toml::from_str(s).map_err(|e| e.to_string())
+    }
+
+    /// Reads `answers.toml` in `day_dir`; a day without one has no known answers.
+    pub fn read(day_dir: &Path) -> io::Result<KnownAnswers> {
+        let path = day_dir.join("answers.toml");
+        match fs::read_to_string(&path) {
+            Ok(s) => KnownAnswers::parse(&s)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(KnownAnswers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The answers expected when solving input from `source`; only examples and `input.txt` have any.
+    pub fn for_source(&self, source: &InputSource) -> Option<&PartAnswers> {
+        match source {
+            InputSource::Example => Some(&self.example),
+            InputSource::Default => Some(&self.input),
+            InputSource::File(_) | InputSource::Stdin => None,
+        }
+    }
+}
+
+/// A part whose answer differs from the known one.
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub part: Part,
+    pub expected: Answer,
+    pub actual: Answer,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "part {}: expected {}, got {}", self.part, self.expected, self.actual)
+    }
+}
+
+/// Solves the input from `source` for every part with a known answer and returns the wrong ones.
+pub fn check_answers<S: Solution>(
+    day_dir: &Path,
+    known: &KnownAnswers,
+    source: &InputSource,
+    params: &Params,
+) -> Result<Vec<Mismatch>, String> {
+    let Some(expected) = known.for_source(source) else {
+        return Ok(Vec::new());
+    };
+    let inputs = source.read(day_dir, &expected.parts()).map_err(|e| e.to_string())?;
+    let mut mismatches = Vec::new();
+    for input in inputs {
+        let answers = solve::<S>(&input.contents, &input.parts, params).map_err(|e| input.diagnostic(e))?;
+        for (part, actual) in answers {
+            if let Some(expected) = expected.get(part)
+                && actual != *expected
+            {
+                mismatches.push(Mismatch { part, expected: expected.clone(), actual });
+            }
+        }
+    }
+    Ok(mismatches)
+}
+
+/// The first input file needed to check the known answers of `source` that cannot be
+/// read, with why; see [`InputSource::missing`].
+pub fn missing_input(day_dir: &Path, known: &KnownAnswers, source: &InputSource) -> Option<(PathBuf, &'static str)> {
+    source.missing(day_dir, &known.for_source(source)?.parts())
+}
+
+/// Panics unless every known answer of `source` in the day's `answers.toml` is reproduced,
+/// with the parameters the workspace's `aoc.toml` gives the day.
+///
+/// Puzzle inputs are personal and not checked in, so when one is missing the
+/// check is skipped, saying why; [`answer_tests!`] also lists it as ignored.
+pub fn assert_answers<S: Solution>(day_dir: &str, answers_toml: &str, source: InputSource) {
+    let known = KnownAnswers::parse(answers_toml).unwrap_or_else(|e| panic!("invalid answers.toml: {}", e));
+    if let Some((path, why)) = missing_input(Path::new(day_dir), &known, &source) {
+        // Written to the stderr handle directly, as the test harness captures `eprintln!`.
+        let _ = writeln!(io::stderr(), "skipped: Day {} answers need {}, which {}", S::DAY, path.display(), why);
+        return;
+    }
+    let params = Config::for_day_dir(Path::new(day_dir))
+        .map_err(|e| e.to_string())
+        .and_then(|config| config.params(S::DAY, S::PARAMS, &source))
+        .unwrap_or_else(|e| panic!("Day {}: {}", S::DAY, e));
+    match check_answers::<S>(Path::new(day_dir), &known, &source, &params) {
+        Ok(mismatches) if mismatches.is_empty() => {}
+        Ok(mismatches) => {
+            let lines: Vec<String> = mismatches.iter().map(|m| m.to_string()).collect();
+            panic!("Day {} gave wrong answers:\n{}", S::DAY, lines.join("\n"));
+        }
+        Err(e) => panic!("Day {} could not be checked: {}", S::DAY, e),
+    }
+}
+
+/// Generates `example_answers` and `input_answers` tests that check the calling
+/// day crate against the answers recorded in its `answers.toml`.
+///
+/// Puzzle inputs are personal and not checked in. The day's `build.rs` sets
+/// `aoc_missing_input` when its input cannot be read, and `input_answers` is then
+/// ignored, with the reason, instead of passing without checking anything.
+#[macro_export]
+macro_rules! answer_tests {
+    ($day:ty) => {
+        #[test]
+        fn example_answers() {
+            $crate::answers::assert_answers::<$day>(
+                env!("CARGO_MANIFEST_DIR"),
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml")),
+                $crate::InputSource::Example,
+            );
+        }
+
+        #[test]
+        #[cfg_attr(aoc_missing_input, ignore = "needs the puzzle input, input.txt or input.txt.enc with its key")]
+        fn input_answers() {
+            $crate::answers::assert_answers::<$day>(
+                env!("CARGO_MANIFEST_DIR"),
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml")),
+                $crate::InputSource::Default,
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, TempDir};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+            crate::parse_int_fields(0, input.trim(), ',')
+        }
+
+        fn part_1(input: &Vec<u64>, _: &Params) -> Result<Answer, String> {
+            Ok(input.iter().sum::<u64>().into())
+        }
+
+        fn part_2(input: &Vec<u64>, _: &Params) -> Result<Answer, String> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let known = KnownAnswers::parse("[example]\npart1 = 357\npart2 = \"abc\"\n\n[input]\npart2 = 8936\n").unwrap();
+        assert_eq!(known.example.get(Part::One), Some(&Answer::Int(357)));
+        assert_eq!(known.example.get(Part::Two), Some(&Answer::from("abc")));
+        assert_eq!(known.input.parts(), vec![Part::Two]);
+        assert_eq!(KnownAnswers::parse("").unwrap(), KnownAnswers::default());
+        assert!(KnownAnswers::parse("[example]\npart3 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_check_answers() {
+        let dir = TempDir::new();
+        dir.file("test-part1.txt", "1,2,3");
+        dir.file("input.txt", "10,20");
+        let known = KnownAnswers::parse("[example]\npart1 = 6\npart2 = 3\n[input]\npart1 = 31\n").unwrap();
+
+        assert!(check_answers::<Sum>(dir.path(), &known, &InputSource::Example, &Params::default()).unwrap().is_empty());
+        assert_eq!(
+            check_answers::<Sum>(dir.path(), &known, &InputSource::Default, &Params::default()).unwrap(),
+            vec![Mismatch { part: Part::One, expected: Answer::Int(31), actual: Answer::Int(30) }]
+        );
+        assert!(check_answers::<Sum>(dir.path(), &known, &InputSource::Stdin, &Params::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let dir = TempDir::new();
+        dir.file("test-part1.txt", "1,2,3");
+        let known = KnownAnswers::parse("[example]\npart1 = 6\n[input]\npart2 = 2\n").unwrap();
+
+        assert_eq!(missing_input(dir.path(), &known, &InputSource::Example), None);
+        let missing = missing_input(dir.path(), &known, &InputSource::Default);
+        assert_eq!(missing, Some((dir.path().join("input.txt"), "does not exist")));
+        assert_eq!(missing_input(dir.path(), &KnownAnswers::default(), &InputSource::Default), None);
+    }
+
+    #[test]
+    fn test_read_without_answers_file() {
+        let dir = TempDir::new();
+        assert_eq!(KnownAnswers::read(dir.path()).unwrap(), KnownAnswers::default());
+    }
+}
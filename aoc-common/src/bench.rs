@@ -0,0 +1,129 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{Params, Part, Solution, SolveError};
+
+/// How often each stage runs: `warmup` untimed runs, then `samples` timed ones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig { warmup: 3, samples: 20 }
+    }
+}
+
+/// Summary of the timed runs of one stage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Statistics of `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let median = if n.is_multiple_of(2) { (sorted[n / 2 - 1] + sorted[n / 2]) / 2 } else { sorted[n / 2] };
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        Stats { mean, median, p95 }
+    }
+}
+
+/// A separately timed step of solving a day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Timings of each stage, in the order they ran.
+pub type Timings = Vec<(Stage, Stats)>;
+
+/// Runs `f` as configured and returns the statistics of the timed runs.
+pub fn measure<T, F: FnMut() -> T>(config: &BenchConfig, mut f: F) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples: Vec<Duration> = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Times parsing `input` and solving each of `parts` on its own.
+///
+/// Every part is solved once before it is timed, so an input it cannot be
+/// solved for fails instead of being timed.
+pub fn bench<S: Solution>(input: &str, parts: &[Part], params: &Params, config: &BenchConfig) -> Result<Timings, SolveError> {
+    let parsed = S::parse(input)?;
+    let mut timings = vec![(Stage::Parse, measure(config, || S::parse(black_box(input))))];
+    for &part in parts {
+        let solved = match part {
+            Part::One => S::part_1(&parsed, params),
+            Part::Two => S::part_2(&parsed, params),
+        };
+        solved.map_err(|message| SolveError::Part(part, message))?;
+        let stats = match part {
+            Part::One => measure(config, || S::part_1(black_box(&parsed), params)),
+            Part::Two => measure(config, || S::part_2(black_box(&parsed), params)),
+        };
+        timings.push((Stage::Solve(part), stats));
+    }
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(ms).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, ms(19));
+
+        let stats = Stats::from_samples(&[ms(4)]);
+        assert_eq!((stats.mean, stats.median, stats.p95), (ms(4), ms(4), ms(4)));
+    }
+
+    #[test]
+    fn test_measure_runs_warmup_and_samples() {
+        let mut runs = 0;
+        measure(&BenchConfig { warmup: 2, samples: 5 }, || runs += 1);
+        assert_eq!(runs, 7);
+    }
+
+    #[test]
+    fn test_stage_display() {
+        assert_eq!(Stage::Parse.to_string(), "parse");
+        assert_eq!(Stage::Solve(Part::Two).to_string(), "part 2");
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+/// Passphrase the puzzle inputs are encrypted with.
+pub const PASSPHRASE_VAR: &str = "AOC_INPUTS_PASSPHRASE";
+/// Key file to use instead of a passphrase.
+pub const KEY_FILE_VAR: &str = "AOC_INPUTS_KEY_FILE";
+/// Gitignored key file in the workspace, used when neither variable is set.
+pub const KEY_FILE: &str = ".aoc-inputs-key";
+
+const HEADER: &str = "aoc-encrypted-input v1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// The encrypted copy of `path`, which is checked in instead of it: `input.txt.enc`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".enc");
+    PathBuf::from(name)
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Secret the inputs are encrypted with: a passphrase, or the contents of a key file.
+pub struct Key {
+    secret: Vec<u8>,
+}
+
+impl Key {
+    pub fn passphrase(passphrase: &str) -> Key {
+        Key { secret: passphrase.as_bytes().to_vec() }
+    }
+
+    /// The key in `path`; trailing whitespace, like a final newline, is not part of it.
+    pub fn read_file(path: &Path) -> io::Result<Key> {
+        let contents = fs::read(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        let len = contents.iter().rposition(|b| !b.is_ascii_whitespace()).map_or(0, |last| last + 1);
+        if len == 0 {
+            return Err(invalid_data(format!("{}: the key file is empty", path.display())));
+        }
+        Ok(Key { secret: contents[..len].to_vec() })
+    }
+
+    /// The passphrase from `AOC_INPUTS_PASSPHRASE`, else the key file named by
+    /// `AOC_INPUTS_KEY_FILE`, else `.aoc-inputs-key` in `workspace`.
+    pub fn from_env(workspace: &Path) -> io::Result<Key> {
+        if let Ok(passphrase) = std::env::var(PASSPHRASE_VAR)
+            && !passphrase.is_empty()
+        {
+            return Ok(Key::passphrase(&passphrase));
+        }
+        if let Some(path) = std::env::var_os(KEY_FILE_VAR) {
+            return Key::read_file(Path::new(&path));
+        }
+        let path = workspace.join(KEY_FILE);
+        if !path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no key to decrypt the inputs: set {} or {}, or create {}", PASSPHRASE_VAR, KEY_FILE_VAR, KEY_FILE),
+            ));
+        }
+        Key::read_file(&path)
+    }
+
+    fn cipher(&self, salt: &[u8]) -> io::Result<ChaCha20Poly1305> {
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(&self.secret, salt, &mut key)
+            .map_err(|e| invalid_data(format!("could not derive the key: {}", e)))?;
+        Ok(ChaCha20Poly1305::new(&key.into()))
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+fn unhex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok()).collect()
+}
+
+fn seal(plaintext: &str, key: &Key, salt: &[u8; SALT_LEN], nonce: &[u8; NONCE_LEN]) -> io::Result<String> {
+    let ciphertext = key
+        .cipher(salt)?
+        .encrypt(Nonce::from_slice(nonce), plaintext.as_bytes())
+        .map_err(|_| invalid_data("could not encrypt"))?;
+    let mut out = format!("{}\nsalt {}\nnonce {}\n", HEADER, hex(salt), hex(nonce));
+    // Wrapped, so the file stays readable to tools that expect text.
+    for chunk in ciphertext.chunks(32) {
+        out.push_str(&hex(chunk));
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// `plaintext` encrypted with `key`, as text that can be checked in.
+///
+/// Every call picks a new salt and nonce, so encrypting the same input twice gives different files.
+pub fn encrypt(plaintext: &str, key: &Key) -> io::Result<String> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    getrandom::getrandom(&mut salt).and_then(|_| getrandom::getrandom(&mut nonce)).map_err(|e| io::Error::other(e.to_string()))?;
+    seal(plaintext, key, &salt, &nonce)
+}
+
+/// The input `encrypted` holds; fails if `key` is not the one it was encrypted with.
+pub fn decrypt(encrypted: &str, key: &Key) -> io::Result<String> {
+    let mut lines = encrypted.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(invalid_data(format!("not an encrypted input, expected `{}` first", HEADER)));
+    }
+    let mut field = |name: &str, len: usize| {
+        lines
+            .next()
+            .and_then(|line| line.strip_prefix(name))
+            .and_then(|value| unhex(value.trim()))
+            .filter(|value| value.len() == len)
+            .ok_or_else(|| invalid_data(format!("the encrypted input has no valid `{}`", name.trim())))
+    };
+    let salt = field("salt ", SALT_LEN)?;
+    let nonce = field("nonce ", NONCE_LEN)?;
+    let ciphertext = unhex(&lines.map(str::trim).collect::<String>())
+        .ok_or_else(|| invalid_data("the encrypted input is not valid hex"))?;
+    let plaintext = key
+        .cipher(&salt)?
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| invalid_data("could not decrypt: wrong key, or the file was changed"))?;
+    String::from_utf8(plaintext).map_err(|_| invalid_data("the decrypted input is not UTF-8"))
+}
+
+/// Reads `path`, or decrypts its encrypted copy with the key of `workspace` if only that exists.
+pub fn read_input(path: &Path, workspace: &Path) -> io::Result<String> {
+    let encrypted = encrypted_path(path);
+    if path.exists() || !encrypted.exists() {
+        return fs::read_to_string(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)));
+    }
+    let contents = fs::read_to_string(&encrypted)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", encrypted.display(), e)))?;
+    Key::from_env(workspace)
+        .and_then(|key| decrypt(&contents, &key))
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", encrypted.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TempDir;
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::passphrase("correct horse");
+        let sealed = seal("3-5\n10-14\n\n1\n", &key, &[7; SALT_LEN], &[9; NONCE_LEN]).unwrap();
+        let lines: Vec<&str> = sealed.lines().collect();
+        assert_eq!(lines[..3], [HEADER, "salt 07070707070707070707070707070707", "nonce 090909090909090909090909"]);
+        assert_eq!(decrypt(&sealed, &key).unwrap(), "3-5\n10-14\n\n1\n");
+
+        let wrong = decrypt(&sealed, &Key::passphrase("battery staple")).unwrap_err();
+        assert!(wrong.to_string().contains("wrong key"));
+        let flipped = if lines[3].starts_with('0') { "1" } else { "0" };
+        let tampered = sealed.replacen(lines[3], &format!("{}{}", flipped, &lines[3][1..]), 1);
+        assert!(decrypt(&tampered, &key).is_err());
+        assert!(decrypt("3-5\n", &key).unwrap_err().to_string().contains("not an encrypted input"));
+    }
+
+    #[test]
+    fn test_encrypt_picks_new_salt() {
+        let key = Key::passphrase("correct horse");
+        let first = encrypt("1\n", &key).unwrap();
+        assert_ne!(first, encrypt("1\n", &key).unwrap());
+        assert_eq!(decrypt(&first, &key).unwrap(), "1\n");
+    }
+
+    #[test]
+    fn test_key_file() {
+        let dir = TempDir::new();
+        let path = dir.file("key", "s3cret\n");
+        let sealed = seal("1\n", &Key::passphrase("s3cret"), &[1; SALT_LEN], &[2; NONCE_LEN]).unwrap();
+        assert_eq!(decrypt(&sealed, &Key::read_file(&path).unwrap()).unwrap(), "1\n");
+        assert!(Key::read_file(&dir.file("empty", "\n")).is_err());
+    }
+
+    #[test]
+    fn test_read_input() {
+        let dir = TempDir::new();
+        dir.file(KEY_FILE, "s3cret");
+        let path = dir.path().join("input.txt");
+        let sealed = seal("42\n", &Key::passphrase("s3cret"), &[1; SALT_LEN], &[2; NONCE_LEN]).unwrap();
+        fs::write(encrypted_path(&path), sealed).unwrap();
+        // The variables would take precedence over the key file.
+        if std::env::var_os(PASSPHRASE_VAR).is_none() && std::env::var_os(KEY_FILE_VAR).is_none() {
+            assert_eq!(read_input(&path, dir.path()).unwrap(), "42\n");
+        }
+        fs::write(&path, "43\n").unwrap();
+        assert_eq!(read_input(&path, dir.path()).unwrap(), "43\n");
+        assert!(read_input(&dir.path().join("other.txt"), dir.path()).unwrap_err().to_string().contains("other.txt"));
+    }
+}
@@ -0,0 +1,199 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Part;
+
+/// Malformed puzzle input, located by line and column (both starting at 1).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    /// The offending piece of input.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error about `text` on the line with the zero-based `line_index`.
+    pub fn new(line_index: usize, column: usize, text: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            file: None,
+            line: line_index + 1,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error about `field`, a slice of `line`; the column is derived from it. A `field`
+    /// that is not a slice of `line` is looked up in it, and put in column 1 if absent.
+    pub fn at(line_index: usize, line: &str, field: &str, message: impl Into<String>) -> ParseError {
+        let offset = (field.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset + field.len() <= line.len() && line.is_char_boundary(offset))
+            .or_else(|| line.find(field));
+        let column = offset.map_or(1, |offset| line[..offset].chars().count() + 1);
+        ParseError::new(line_index, column, field, message)
+    }
+
+    pub fn with_file(mut self, file: &Path) -> ParseError {
+        self.file = Some(file.to_path_buf());
+        self
+    }
+
+    /// Multi-line diagnostic that quotes the offending line of `input` and marks the text.
+    pub fn render(&self, input: &str) -> String {
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("line {}, column {}", self.line, self.column),
+        };
+        let mut out = format!("error: {}\n  --> {}\n", self.message, location);
+        if let Some(source) = input.split('\n').nth(self.line.saturating_sub(1)) {
+            let source = source.trim_end_matches('\r');
+            let number = self.line.to_string();
+            let gutter = " ".repeat(number.len());
+            let marker = "^".repeat(self.text.chars().count().max(1));
+            out.push_str(&format!("{} |\n{} | {}\n", gutter, number, source));
+            out.push_str(&format!("{} | {}{}\n", gutter, " ".repeat(self.column.saturating_sub(1)), marker));
+        }
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: {}: `{}`", self.line, self.column, self.message, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Why a day could not answer an input.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolveError {
+    /// The input does not parse.
+    Parse(ParseError),
+    /// The input parses, but the part cannot be solved for it, like day 8 with
+    /// fewer circuits left than it multiplies.
+    Part(Part, String),
+}
+
+impl SolveError {
+    /// Multi-line diagnostic; parse errors quote the offending line of `input`.
+    pub fn render(&self, input: &str) -> String {
+        match self {
+            SolveError::Parse(e) => e.render(input),
+            SolveError::Part(part, message) => format!("error: part {}: {}\n", part, message),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::Part(part, message) => write!(f, "part {}: {}", part, message),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+/// Failure to read or to parse a puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "{}", e),
+            InputError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            InputError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(e: ParseError) -> Self {
+        InputError::Parse(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_computes_column() {
+        let line = "11-22,95-x15";
+        let field = &line[6..];
+        let err = ParseError::at(2, line, field, "expected a number");
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 7);
+        assert_eq!(err.text, "95-x15");
+
+        let line = "é,x";
+        assert_eq!(ParseError::at(0, line, &line[3..], "expected a number").column, 3);
+        assert_eq!(ParseError::at(0, line, "x", "expected a number").column, 3);
+        assert_eq!(ParseError::at(0, line, "y", "expected a number").column, 1);
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(0, 4, "x", "expected a digit").with_file(Path::new("day03/input.txt"));
+        assert_eq!(err.to_string(), "day03/input.txt:1:4: expected a digit: `x`");
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "3-5\n10-1x\n";
+        let err = ParseError::new(1, 4, "1x", "expected a number");
+        assert_eq!(
+            err.render(input),
+            "error: expected a number\n  --> line 2, column 4\n  |\n2 | 10-1x\n  |    ^^\n"
+        );
+        assert_eq!(SolveError::from(err.clone()).render(input), err.render(input));
+
+        let unplaced = ParseError { line: 0, column: 0, ..err };
+        assert_eq!(
+            unplaced.render(input),
+            "error: expected a number\n  --> line 0, column 0\n  |\n0 | 3-5\n  | ^^\n"
+        );
+    }
+
+    #[test]
+    fn test_part_error() {
+        let err = SolveError::Part(Part::One, "only 2 circuits".to_string());
+        assert_eq!(err.to_string(), "part 1: only 2 circuits");
+        assert_eq!(err.render("1,2,3\n"), "error: part 1: only 2 circuits\n");
+    }
+}
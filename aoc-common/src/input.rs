@@ -0,0 +1,188 @@
+use std::fs;
+use std::io;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Reads the whole file into a string.
+pub fn read_file(filename: &str) -> io::Result<String> {
+    fs::read_to_string(filename)
+}
+
+/// Reads the file and splits it into lines.
+pub fn read_lines(filename: &str) -> io::Result<Vec<String>> {
+    let contents = read_file(filename)?;
+    Ok(lines(&contents).into_iter().map(|s| s.to_string()).collect())
+}
+
+/// Line of input paired with its zero-based index in the whole input.
+pub type NumberedLine<'a> = (usize, &'a str);
+
+/// Lines of the input without their line endings.
+///
+/// Both "\n" and "\r\n" end a line, and blank lines at the end of the input
+/// are dropped. Blank lines in the middle are kept.
+pub fn lines(input: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    while lines.last().is_some_and(|line| is_blank(line)) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Whether the line holds nothing but whitespace.
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// The lines that are not blank, numbered by their position in the input.
+pub fn non_blank_lines(input: &str) -> Vec<NumberedLine<'_>> {
+    input.lines().enumerate().filter(|(_, line)| !is_blank(line)).collect()
+}
+
+/// Groups of lines separated by one or more blank lines.
+///
+/// Blank lines at the start and end of the input do not create empty sections.
+pub fn sections(input: &str) -> Vec<Vec<NumberedLine<'_>>> {
+    let mut sections = Vec::new();
+    let mut current = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if is_blank(line) {
+            if !current.is_empty() {
+                sections.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push((i, line));
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// Parses every `separator` delimited field of `s` as an integer.
+pub fn parse_ints<T: FromStr>(s: &str, separator: char) -> Result<Vec<T>, T::Err> {
+    s.split(separator).map(|p| p.trim().parse::<T>()).collect()
+}
+
+/// Parses every whitespace delimited field of `s` as an integer.
+pub fn parse_ints_whitespace<T: FromStr>(s: &str) -> Result<Vec<T>, T::Err> {
+    s.split_whitespace().map(|p| p.parse::<T>()).collect()
+}
+
+/// Parses `field`, a slice of `line`, as an integer, reporting failures at its position.
+pub fn parse_int<T: FromStr>(line_index: usize, line: &str, field: &str) -> Result<T, ParseError> {
+    field
+        .parse::<T>()
+        .map_err(|_| ParseError::at(line_index, line, field, "expected a number"))
+}
+
+/// Like [`parse_ints`], but errors point at the offending field of `line`.
+pub fn parse_int_fields<T: FromStr>(line_index: usize, line: &str, separator: char) -> Result<Vec<T>, ParseError> {
+    line.split(separator)
+        .map(|field| parse_int(line_index, line, field.trim()))
+        .collect()
+}
+
+/// Like [`parse_ints_whitespace`], but errors point at the offending field of `line`.
+pub fn parse_int_fields_whitespace<T: FromStr>(line_index: usize, line: &str) -> Result<Vec<T>, ParseError> {
+    line.split_whitespace()
+        .map(|field| parse_int(line_index, line, field))
+        .collect()
+}
+
+/// Embeds the example input of the calling day crate, `example!(1)` for
+/// `test-part1.txt` and `example!(2)` for `test-part2.txt`.
+///
+/// The file is read at compile time, so tests do not depend on the working directory.
+#[macro_export]
+macro_rules! example {
+    ($part:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/test-part", $part, ".txt"))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TempDir;
+
+    #[test]
+    fn test_read_lines() {
+        let dir = TempDir::new();
+        let test_file = dir.file("read.txt", "line1\nline2\nline3");
+
+        let result = read_lines(test_file.to_str().unwrap()).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0], "line1");
+        assert_eq!(result[1], "line2");
+        assert_eq!(result[2], "line3");
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let dir = TempDir::new();
+        assert!(read_file(dir.path().join("does_not_exist.txt").to_str().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_read_lines_trailing_newline() {
+        let dir = TempDir::new();
+        let test_file = dir.file("read.txt", "line1\r\nline2\r\n");
+
+        let result = read_lines(test_file.to_str().unwrap()).unwrap();
+
+        assert_eq!(result, vec!["line1", "line2"]);
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines("a\nb\n"), vec!["a", "b"]);
+        assert_eq!(lines("a\r\n\r\nb"), vec!["a", "", "b"]);
+        assert_eq!(lines("a\n\n  \n"), vec!["a"]);
+        assert!(lines("").is_empty());
+    }
+
+    #[test]
+    fn test_non_blank_lines() {
+        let result = non_blank_lines("\na\n  \nb\r\n\n");
+        assert_eq!(result, vec![(1, "a"), (3, "b")]);
+    }
+
+    #[test]
+    fn test_sections() {
+        let result = sections("3-5\r\n10-14\r\n\r\n\r\n1\r\n5\r\n");
+        assert_eq!(result, vec![vec![(0, "3-5"), (1, "10-14")], vec![(4, "1"), (5, "5")]]);
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_ints() {
+        let result: Vec<u64> = parse_ints("162,817,812", ',').unwrap();
+        assert_eq!(result, vec![162, 817, 812]);
+        assert!(parse_ints::<u64>("1,x", ',').is_err());
+    }
+
+    #[test]
+    fn test_parse_int_fields() {
+        let result: Vec<u64> = parse_int_fields(0, "7, 1", ',').unwrap();
+        assert_eq!(result, vec![7, 1]);
+
+        let err = parse_int_fields::<u64>(4, "7,1,x2", ',').unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (5, 5, "x2"));
+    }
+
+    #[test]
+    fn test_parse_int_fields_whitespace() {
+        let err = parse_int_fields_whitespace::<u64>(0, " 45 6a  387").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (5, "6a"));
+    }
+
+    #[test]
+    fn test_parse_ints_whitespace() {
+        let result: Vec<u64> = parse_ints_whitespace(" 45 64  387 23 ").unwrap();
+        assert_eq!(result, vec![45, 64, 387, 23]);
+    }
+}
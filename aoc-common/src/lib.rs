@@ -0,0 +1,30 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod encrypted;
+pub mod error;
+pub mod input;
+pub mod params;
+pub mod random;
+pub mod solution;
+pub mod source;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub use answer::Answer;
+pub use answers::{KnownAnswers, PartAnswers};
+pub use bench::{BenchConfig, Stage, Stats, Timings};
+pub use error::{InputError, ParseError, SolveError};
+pub use input::{
+    NumberedLine, is_blank, lines, non_blank_lines, parse_int, parse_int_fields, parse_int_fields_whitespace,
+    parse_ints, parse_ints_whitespace, read_file, read_lines, sections,
+};
+pub use params::{Config, ParamSpec, Params, CONFIG_FILE};
+pub use random::Rng;
+pub use solution::{Answers, Day, Part, Solution, Solved, day_main, solve, solve_timed};
+pub use source::{InputSource, LoadedInput};
+#[cfg(any(test, feature = "testing"))]
+pub use testing::{MockServer, TempDir};
+/// Re-exported so days can log their intermediate state with `aoc_common::tracing::debug!`
+/// and friends; the runner decides whether anything is printed.
+pub use tracing;
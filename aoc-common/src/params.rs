@@ -0,0 +1,211 @@
+//! Named parameters of the solvers, like the number of connections day 8 makes,
+//! so variants can be tried without recompiling.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+use crate::InputSource;
+
+/// A parameter a day declares, with the value it has unless configured otherwise
+/// and the values it accepts.
+pub type ParamSpec = (&'static str, i64, RangeInclusive<i64>);
+
+/// Values of a day's parameters; every declared parameter has one, within its range.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, (i64, RangeInclusive<i64>)>,
+}
+
+impl Params {
+    pub fn defaults(specs: &[ParamSpec]) -> Params {
+        Params { values: specs.iter().map(|(name, value, range)| (name.to_string(), (*value, range.clone()))).collect() }
+    }
+
+    /// Changes a declared parameter; fails if the day has no such parameter or
+    /// `value` is outside its range.
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        if let Some((current, range)) = self.values.get_mut(name) {
+            if !range.contains(&value) {
+                let expected = match (*range.start(), *range.end()) {
+                    (start, i64::MAX) => format!("at least {}", start),
+                    (start, end) => format!("between {} and {}", start, end),
+                };
+                return Err(format!("`{}` must be {}, got {}", name, expected, value));
+            }
+            *current = value;
+            return Ok(());
+        }
+        if self.values.is_empty() {
+            return Err(format!("there is no parameter `{}`; this day has none", name));
+        }
+        let names: Vec<&str> = self.values.keys().map(String::as_str).collect();
+        Err(format!("there is no parameter `{}`; known: {}", name, names.join(", ")))
+    }
+
+    /// The value of `name`, which [`Params::set`] kept within its declared range.
+    ///
+    /// Panics if the day did not declare `name`, or declared a range that does not fit in `T`.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> T {
+        let (value, _) = self.values.get(name).unwrap_or_else(|| panic!("undeclared parameter `{}`", name));
+        T::try_from(*value).unwrap_or_else(|_| panic!("the range of parameter `{}` does not fit its type: {}", name, value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.values.iter().map(|(name, &(value, _))| (name.as_str(), value))
+    }
+}
+
+/// `name=value` for every parameter, by name, separated by commas: `boxes=3,connections=10`.
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values: Vec<String> = self.iter().map(|(name, value)| format!("{}={}", name, value)).collect();
+        write!(f, "{}", values.join(","))
+    }
+}
+
+/// Name of the parameter file in the workspace root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Default, PartialEq)]
+struct DayConfig {
+    all: Vec<(String, i64)>,
+    example: Vec<(String, i64)>,
+}
+
+/// Parameters from `aoc.toml`:
+///
+/// ```toml
+/// [day08]
+/// connections = 1000
+///
+/// [day08.example]
+/// connections = 10
+/// ```
+///
+/// `[dayNN]` applies to every input of the day, `[dayNN.example]` only to its examples.
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    days: BTreeMap<u8, DayConfig>,
+}
+
+fn integers(table: &toml::Table, at: &str) -> Result<Vec<(String, i64)>, String> {
+    table
+        .iter()
+        .map(|(name, value)| match value {
+            toml::Value::Integer(n) => Ok((name.clone(), *n)),
+            _ => Err(format!("{}.{} must be an integer", at, name)),
+        })
+        .collect()
+}
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Config, String> {
+        let table: toml::Table = toml::from_str(s).map_err(|e| e.to_string())?;
+        let mut days = BTreeMap::new();
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .filter(|n| n.len() == 2)
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or_else(|| format!("expected tables like [day08], found `{}`", key))?;
+            let toml::Value::Table(mut params) = value else {
+                return Err(format!("{} must be a table", key));
+            };
+            let example = match params.remove("example") {
+                Some(toml::Value::Table(example)) => integers(&example, &format!("{}.example", key))?,
+                Some(_) => return Err(format!("{}.example must be a table", key)),
+                None => Vec::new(),
+            };
+            days.insert(day, DayConfig { all: integers(&params, &key)?, example });
+        }
+        Ok(Config { days })
+    }
+
+    /// Reads `path`; without the file there is nothing to configure.
+    pub fn read(path: &Path) -> io::Result<Config> {
+        match fs::read_to_string(path) {
+            Ok(s) => Config::parse(&s)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads the `aoc.toml` of the workspace `day_dir` belongs to.
+    pub fn for_day_dir(day_dir: &Path) -> io::Result<Config> {
+        match day_dir.parent() {
+            Some(workspace) => Config::read(&workspace.join(CONFIG_FILE)),
+            None => Ok(Config::default()),
+        }
+    }
+
+    /// The parameters of `day` when solving input from `source`: the declared
+    /// defaults, then `[dayNN]`, then `[dayNN.example]` for the examples.
+    pub fn params(&self, day: u8, specs: &[ParamSpec], source: &InputSource) -> Result<Params, String> {
+        let mut params = Params::defaults(specs);
+        if let Some(config) = self.days.get(&day) {
+            let example: &[(String, i64)] = if *source == InputSource::Example { &config.example } else { &[] };
+            for (name, value) in config.all.iter().chain(example) {
+                params.set(name, *value).map_err(|e| format!("{}: day{:02}: {}", CONFIG_FILE, day, e))?;
+            }
+        }
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECS: &[ParamSpec] = &[("connections", 1000, 0..=i64::MAX), ("boxes", 3, 1..=255)];
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::defaults(SPECS);
+        assert_eq!(params.get::<usize>("connections"), 1000);
+        params.set("connections", 10).unwrap();
+        assert_eq!(params.get::<u8>("connections"), 10);
+        assert_eq!(params.set("conections", 1).unwrap_err(), "there is no parameter `conections`; known: boxes, connections");
+        assert!(Params::defaults(&[]).set("x", 1).unwrap_err().contains("has none"));
+        assert_eq!(params.to_string(), "boxes=3,connections=10");
+        assert_eq!(Params::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_params_out_of_range() {
+        let mut params = Params::defaults(SPECS);
+        assert_eq!(params.set("boxes", 0).unwrap_err(), "`boxes` must be between 1 and 255, got 0");
+        assert_eq!(params.set("connections", -1).unwrap_err(), "`connections` must be at least 0, got -1");
+        assert_eq!((params.get::<u8>("boxes"), params.get::<usize>("connections")), (3, 1000));
+        params.set("boxes", 255).unwrap();
+        assert_eq!(params.get::<u8>("boxes"), 255);
+    }
+
+    #[test]
+    fn test_config() {
+        let config = Config::parse("[day08]\nboxes = 4\n\n[day08.example]\nconnections = 10\n").unwrap();
+        let example = config.params(8, SPECS, &InputSource::Example).unwrap();
+        assert_eq!((example.get::<i64>("connections"), example.get::<i64>("boxes")), (10, 4));
+        let input = config.params(8, SPECS, &InputSource::Default).unwrap();
+        assert_eq!((input.get::<i64>("connections"), input.get::<i64>("boxes")), (1000, 4));
+        assert_eq!(config.params(3, &[("length", 2, 1..=9)], &InputSource::Example).unwrap().get::<i64>("length"), 2);
+        let negative = Config::parse("[day08]\nconnections = -5\n").unwrap();
+        let error = negative.params(8, SPECS, &InputSource::Default).unwrap_err();
+        assert_eq!(error, "aoc.toml: day08: `connections` must be at least 0, got -5");
+
+        assert!(config.params(8, &[], &InputSource::Default).unwrap_err().starts_with("aoc.toml: day08: there is no parameter `boxes`"));
+        assert!(Config::parse("[day8]\nx = 1\n").is_err());
+        assert!(Config::parse("[day08]\nx = \"1\"\n").is_err());
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_read_without_config_file() {
+        let dir = crate::TempDir::new();
+        assert_eq!(Config::for_day_dir(&dir.path().join("day08")).unwrap(), Config::default());
+    }
+}
@@ -0,0 +1,107 @@
+use std::ops::RangeInclusive;
+
+use crate::{Params, Part, Solution, solve};
+
+/// Random numbers for generating puzzle inputs.
+///
+/// SplitMix64: small and fast, and a seed gives the same numbers on every
+/// platform and in every version, so a generated input can be reproduced
+/// from its size and seed alone.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {}..={}", low, high);
+        match (high - low).checked_add(1) {
+            Some(len) => low + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        assert!(!items.is_empty(), "nothing to pick from");
+        &items[self.range(0..=items.len() as u64 - 1) as usize]
+    }
+}
+
+/// Generates an input for `S`, or `None` if the day has no generator.
+pub fn generate<S: Solution>(size: usize, seed: u64) -> Option<String> {
+    S::generate(size, &mut Rng::new(seed))
+}
+
+/// Panics unless the inputs generated for `S` at each of `sizes`, with a few
+/// seeds, come out the same for the same seed, parse, and solve with `params`.
+pub fn assert_generated<S: Solution>(sizes: &[usize], params: &Params) {
+    assert_generated_parts::<S>(sizes, &Part::ALL, params);
+}
+
+/// [`assert_generated`] for a day that only solves `parts` so far.
+pub fn assert_generated_parts<S: Solution>(sizes: &[usize], parts: &[Part], params: &Params) {
+    for &size in sizes {
+        for seed in 0..3 {
+            let input = generate::<S>(size, seed).unwrap_or_else(|| panic!("Day {} has no generator", S::DAY));
+            assert_eq!(generate::<S>(size, seed), Some(input.clone()), "Day {}: seed {} is not reproducible", S::DAY, seed);
+            if let Err(e) = solve::<S>(&input, parts, params) {
+                panic!("Day {}: input of size {} with seed {} cannot be solved:\n{}", S::DAY, size, seed, e.render(&input));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeds_are_reproducible() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+        // Inputs generated from a seed must not change between versions.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(7);
+        let rolls: Vec<u64> = (0..1000).map(|_| rng.range(1..=6)).collect();
+        assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+        assert!((1..=6).all(|side| rolls.contains(&side)));
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(0..=u64::MAX);
+        assert!((0..100).all(|_| !rng.chance(0.0) && rng.chance(1.0)));
+        assert_eq!(*rng.pick(&['x']), 'x');
+    }
+
+    #[test]
+    #[should_panic(expected = "nothing to pick from")]
+    fn test_pick_from_nothing() {
+        Rng::new(7).pick::<char>(&[]);
+    }
+}
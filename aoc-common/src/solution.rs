@@ -0,0 +1,277 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crate::{Answer, BenchConfig, Config, InputError, InputSource, ParamSpec, Params, ParseError, Rng, SolveError, Timings};
+
+/// A day's puzzle: parses the input once and solves both parts from it.
+///
+/// The parts compute with whatever types suit them and return an [`Answer`],
+/// usually with `Ok(...into())`, or why the input cannot be solved when it
+/// parses but breaks an assumption of the puzzle.
+pub trait Solution {
+    const DAY: u8;
+    /// Named parameters of the parts with their defaults, like `&[("connections", 1000, 0..=u32::MAX as i64)]`;
+    /// `aoc.toml` and the runner's `--param` change them.
+    const PARAMS: &'static [ParamSpec] = &[];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input, params: &Params) -> Result<Answer, String>;
+    fn part_2(input: &Self::Input, params: &Params) -> Result<Answer, String>;
+
+    /// A random valid input of the given size, for stress tests and benchmarks;
+    /// `None` for days without a generator.
+    fn generate(_size: usize, _rng: &mut Rng) -> Option<String> {
+        None
+    }
+
+    /// Parses everything `reader` yields.
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self::Input, InputError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(Self::parse(&input)?)
+    }
+
+    /// Reads and parses the file at `path`; parse errors name the file.
+    fn parse_file<P: AsRef<Path>>(path: P) -> Result<Self::Input, InputError> {
+        let path = path.as_ref();
+        let file = fs::File::open(path)?;
+        Self::parse_reader(io::BufReader::new(file)).map_err(|e| match e {
+            InputError::Parse(e) => InputError::Parse(e.with_file(path)),
+            e => e,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// 1 or 2, as the puzzle numbers it.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Answers of the solved parts.
+pub type Answers = Vec<(Part, Answer)>;
+
+/// Answers of one input, with how long parsing and each part took.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<(Part, Answer, Duration)>,
+}
+
+/// Parses `input` and solves the requested parts, timing each step once.
+///
+/// For timings that can be compared between runs, use [`crate::bench::bench`].
+pub fn solve_timed<S: Solution>(input: &str, parts: &[Part], params: &Params) -> Result<Solved, SolveError> {
+    let start = Instant::now();
+    let parsed = tracing::debug_span!("parse", day = S::DAY).in_scope(|| S::parse(input))?;
+    let parse_time = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let _span = tracing::debug_span!("solve", day = S::DAY, part = part.number()).entered();
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_1(&parsed, params),
+                Part::Two => S::part_2(&parsed, params),
+            };
+            let elapsed = start.elapsed();
+            let answer = answer.map_err(|message| SolveError::Part(part, message))?;
+            tracing::debug!(%answer, ?elapsed, "solved");
+            Ok((part, answer, elapsed))
+        })
+        .collect::<Result<_, SolveError>>()?;
+    Ok(Solved { parse_time, answers })
+}
+
+/// Parses `input` and solves the requested parts.
+pub fn solve<S: Solution>(input: &str, parts: &[Part], params: &Params) -> Result<Answers, SolveError> {
+    let solved = solve_timed::<S>(input, parts, params)?;
+    Ok(solved.answers.into_iter().map(|(part, answer, _)| (part, answer)).collect())
+}
+
+/// Type-erased handle to a [`Solution`], so days can be kept in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub params: &'static [ParamSpec],
+    pub solve: fn(&str, &[Part], &Params) -> Result<Answers, SolveError>,
+    pub solve_timed: fn(&str, &[Part], &Params) -> Result<Solved, SolveError>,
+    pub bench: fn(&str, &[Part], &Params, &BenchConfig) -> Result<Timings, SolveError>,
+    /// Generates an input of a size from a seed.
+    pub generate: fn(usize, u64) -> Option<String>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            params: S::PARAMS,
+            solve: solve::<S>,
+            solve_timed: solve_timed::<S>,
+            bench: crate::bench::bench::<S>,
+            generate: crate::random::generate::<S>,
+        }
+    }
+}
+
+/// Entry point of a day's own binary: `dayNN [--input <path> | --example | -]`.
+///
+/// Parameters come from the workspace's `aoc.toml`, as they do for the runner.
+pub fn day_main<S: Solution>(day_dir: &str) -> ExitCode {
+    let source = match InputSource::from_args(std::env::args().skip(1)) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let params = Config::for_day_dir(Path::new(day_dir))
+        .map_err(|e| e.to_string())
+        .and_then(|config| config.params(S::DAY, S::PARAMS, &source));
+    let params = match params {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let inputs = match source.read(Path::new(day_dir), &Part::ALL) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Something went wrong reading the input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    for input in inputs {
+        match solve::<S>(&input.contents, &input.parts, &params) {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    println!("Answer for part {}: {}", part, answer);
+                }
+            }
+            Err(e) => {
+                eprint!("{}", input.diagnostic(e));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            crate::parse_int_fields(0, input, ',')
+        }
+
+        fn part_1(input: &Vec<u32>, _: &Params) -> Result<Answer, String> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part_2(input: &Vec<u32>, _: &Params) -> Result<Answer, String> {
+            match input.len() {
+                n if n > 3 => Err(format!("{} numbers, can only count 3", n)),
+                n => Ok(n.into()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let result = solve::<Sum>("1,2,3", &Part::ALL, &Params::default()).unwrap();
+        assert_eq!(result, vec![(Part::One, Answer::Int(6)), (Part::Two, Answer::Int(3))]);
+    }
+
+    #[test]
+    fn test_solve_timed() {
+        let solved = solve_timed::<Sum>("1,2,3", &[Part::Two], &Params::default()).unwrap();
+        let answers: Vec<_> = solved.answers.iter().map(|(part, answer, _)| (*part, answer.clone())).collect();
+        assert_eq!(answers, vec![(Part::Two, Answer::Int(3))]);
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        let Err(SolveError::Parse(err)) = solve::<Sum>("1,x", &Part::ALL, &Params::default()) else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+
+    #[test]
+    fn test_solve_part_error() {
+        let err = solve::<Sum>("1,2,3,4", &Part::ALL, &Params::default()).unwrap_err();
+        assert_eq!(err, SolveError::Part(Part::Two, "4 numbers, can only count 3".to_string()));
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let result = Sum::parse_reader(io::Cursor::new("7,8")).unwrap();
+        assert_eq!(result, vec![7, 8]);
+    }
+
+    #[test]
+    fn test_parse_file() {
+        let dir = crate::TempDir::new();
+        let test_file = dir.file("parse.txt", "1,2");
+
+        let result = Sum::parse_file(&test_file).unwrap();
+
+        assert_eq!(result, vec![1, 2]);
+        assert!(matches!(Sum::parse_file(dir.path().join("does_not_exist.txt")), Err(InputError::Io(_))));
+
+        let test_file = dir.file("parse.txt", "1,b");
+        match Sum::parse_file(&test_file) {
+            Err(InputError::Parse(e)) => assert_eq!(e.file.unwrap(), test_file),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_day_handle() {
+        let day = Day::new::<Sum>();
+        assert_eq!(day.day, 0);
+        assert_eq!((day.solve)("4,5", &[Part::Two], &Params::default()).unwrap(), vec![(Part::Two, Answer::Int(2))]);
+
+        let config = BenchConfig { warmup: 0, samples: 1 };
+        let timings = (day.bench)("4,5", &Part::ALL, &Params::default(), &config).unwrap();
+        let stages: Vec<_> = timings.iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(stages, vec![crate::Stage::Parse, crate::Stage::Solve(Part::One), crate::Stage::Solve(Part::Two)]);
+    }
+}
@@ -0,0 +1,208 @@
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{encrypted, Part, SolveError};
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum InputSource {
+    /// `input.txt` in the day's directory.
+    #[default]
+    Default,
+    /// `test-part1.txt`, or `test-part2.txt` for part 2 when the day has one.
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses `--input <path>`, `--example` and `-` (stdin) from the command line.
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<InputSource, String> {
+        let mut source = InputSource::Default;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let next = match arg.as_str() {
+                "--input" => match args.next() {
+                    Some(path) if path == "-" => InputSource::Stdin,
+                    Some(path) => InputSource::File(PathBuf::from(path)),
+                    None => return Err("--input needs a path".to_string()),
+                },
+                "--example" => InputSource::Example,
+                "-" => InputSource::Stdin,
+                _ => return Err(format!("unexpected argument `{}`", arg)),
+            };
+            if source != InputSource::Default {
+                return Err("only one of --input, --example and - can be given".to_string());
+            }
+            source = next;
+        }
+        Ok(source)
+    }
+
+    /// The file holding the input for `part`, or `None` when reading stdin.
+    pub fn path(&self, day_dir: &Path, part: Part) -> Option<PathBuf> {
+        match self {
+            InputSource::Default => Some(day_dir.join("input.txt")),
+            InputSource::Example => Some(example_path(day_dir, part)),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// The first file needed for `parts` that cannot be read, with why: it does not
+    /// exist, or it only exists encrypted and the workspace `day_dir` is in has no key.
+    pub fn missing(&self, day_dir: &Path, parts: &[Part]) -> Option<(PathBuf, &'static str)> {
+        let workspace = day_dir.parent().unwrap_or(day_dir);
+        parts.iter().filter_map(|&part| self.path(day_dir, part)).find_map(|path| {
+            let why = if path.exists() {
+                None
+            } else if !encrypted::encrypted_path(&path).exists() {
+                Some("does not exist")
+            } else if encrypted::Key::from_env(workspace).is_err() {
+                Some("is encrypted and there is no key")
+            } else {
+                None
+            };
+            why.map(|why| (path, why))
+        })
+    }
+
+    /// Reads the input for each part, reading every distinct source only once.
+    ///
+    /// A file that only exists encrypted, as `input.txt.enc`, is decrypted with the
+    /// key of the workspace `day_dir` is in.
+    pub fn read(&self, day_dir: &Path, parts: &[Part]) -> io::Result<Vec<LoadedInput>> {
+        let mut sources: Vec<(Option<PathBuf>, Vec<Part>)> = Vec::new();
+        for &part in parts {
+            let path = self.path(day_dir, part);
+            match sources.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, shared)) => shared.push(part),
+                None => sources.push((path, vec![part])),
+            }
+        }
+        sources
+            .into_iter()
+            .map(|(path, parts)| {
+                let contents = match &path {
+                    Some(path) => encrypted::read_input(path, day_dir.parent().unwrap_or(day_dir))?,
+                    None => {
+                        let mut contents = String::new();
+                        io::stdin().read_to_string(&mut contents)?;
+                        contents
+                    }
+                };
+                Ok(LoadedInput { path, contents, parts })
+            })
+            .collect()
+    }
+}
+
+/// Puzzle input read for one or more parts.
+#[derive(Debug)]
+pub struct LoadedInput {
+    /// `None` when read from stdin.
+    pub path: Option<PathBuf>,
+    pub contents: String,
+    pub parts: Vec<Part>,
+}
+
+impl LoadedInput {
+    /// Renders why this input could not be solved, naming the file it came from.
+    pub fn diagnostic(&self, e: SolveError) -> String {
+        let path = self.path.as_deref().unwrap_or(Path::new("<stdin>"));
+        match e {
+            SolveError::Parse(e) => e.with_file(path).render(&self.contents),
+            e => format!("{}  --> {}\n", e.render(&self.contents), path.display()),
+        }
+    }
+}
+
+fn example_path(day_dir: &Path, part: Part) -> PathBuf {
+    let part2 = day_dir.join("test-part2.txt");
+    if part == Part::Two && part2.exists() {
+        part2
+    } else {
+        day_dir.join("test-part1.txt")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TempDir;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(InputSource::from_args(args(&[])), Ok(InputSource::Default));
+        assert_eq!(InputSource::from_args(args(&["--example"])), Ok(InputSource::Example));
+        assert_eq!(InputSource::from_args(args(&["-"])), Ok(InputSource::Stdin));
+        assert_eq!(InputSource::from_args(args(&["--input", "-"])), Ok(InputSource::Stdin));
+        assert_eq!(
+            InputSource::from_args(args(&["--input", "other.txt"])),
+            Ok(InputSource::File(PathBuf::from("other.txt")))
+        );
+    }
+
+    #[test]
+    fn test_from_args_errors() {
+        assert!(InputSource::from_args(args(&["--input"])).is_err());
+        assert!(InputSource::from_args(args(&["--example", "-"])).is_err());
+        assert!(InputSource::from_args(args(&["--verbose"])).is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        let dir = Path::new("day05");
+        assert_eq!(InputSource::Default.path(dir, Part::One), Some(dir.join("input.txt")));
+        assert_eq!(InputSource::Example.path(dir, Part::Two), Some(dir.join("test-part1.txt")));
+        assert_eq!(InputSource::Stdin.path(dir, Part::One), None);
+    }
+
+    #[test]
+    fn test_read_shares_one_file() {
+        let source = InputSource::File(PathBuf::from("Cargo.toml"));
+        let inputs = source.read(Path::new("."), &Part::ALL).unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].parts, vec![Part::One, Part::Two]);
+        assert!(inputs[0].contents.contains("aoc-common"));
+    }
+
+    #[test]
+    fn test_read_missing_file_names_path() {
+        let err = InputSource::Default.read(Path::new("no_such_day"), &Part::ALL).unwrap_err();
+        assert!(err.to_string().contains("input.txt"));
+    }
+
+    #[test]
+    fn test_missing() {
+        let dir = TempDir::new();
+        dir.file("test-part1.txt", "1,2,3");
+        let input = dir.path().join("input.txt");
+        assert_eq!(InputSource::Example.missing(dir.path(), &Part::ALL), None);
+        assert_eq!(InputSource::Default.missing(dir.path(), &Part::ALL), Some((input.clone(), "does not exist")));
+        assert_eq!(InputSource::Stdin.missing(dir.path(), &Part::ALL), None);
+
+        // Without a key an encrypted input cannot be read either.
+        dir.file("input.txt.enc", "aoc-encrypted-input v1\n");
+        if std::env::var_os(encrypted::PASSPHRASE_VAR).is_none() && std::env::var_os(encrypted::KEY_FILE_VAR).is_none() {
+            let missing = InputSource::Default.missing(dir.path(), &[Part::Two]);
+            assert_eq!(missing, Some((input, "is encrypted and there is no key")));
+        }
+
+        dir.file("input.txt", "4,5");
+        assert_eq!(InputSource::Default.missing(dir.path(), &Part::ALL), None);
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = LoadedInput { path: None, contents: "1,2\n".to_string(), parts: vec![Part::One] };
+        let parse = crate::ParseError::new(0, 3, "2", "expected three coordinates");
+        assert!(input.diagnostic(parse.into()).contains("--> <stdin>:1:3"));
+        let part = SolveError::Part(Part::One, "only one box".to_string());
+        assert_eq!(input.diagnostic(part), "error: part 1: only one box\n  --> <stdin>\n");
+    }
+}
@@ -0,0 +1,148 @@
+//! Support for tests: scratch directories and a stand-in for the Advent of Code
+//! website. Only built for tests and with the `testing` feature, which the other
+//! crates enable for theirs.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Scratch directory that is removed, together with its contents, when dropped.
+///
+/// Every instance gets its own directory, so tests running in parallel never
+/// share files, and cleanup still happens when an assertion panics.
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new() -> TempDir {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("aoc-test-{}-{}", std::process::id(), id));
+        fs::create_dir_all(&path).unwrap_or_else(|e| panic!("could not create {}: {}", path.display(), e));
+        TempDir { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes `contents` to the file `name` in this directory and returns its path.
+    pub fn file(&self, name: &str, contents: &str) -> PathBuf {
+        let path = self.path.join(name);
+        fs::write(&path, contents).unwrap_or_else(|e| panic!("could not write {}: {}", path.display(), e));
+        path
+    }
+}
+
+impl Default for TempDir {
+    fn default() -> Self {
+        TempDir::new()
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// HTTP server on localhost that answers each request with the next canned
+/// `(status, body)` response and records the requests it received.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("could not bind the mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+        let responses: Vec<(u16, String)> = responses.into_iter().map(|(s, b)| (s, b.to_string())).collect();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((mut stream, _)) = listener.accept() else { return };
+                let mut reader = BufReader::new(&mut stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                    request.push_str(&line);
+                }
+                let mut payload = vec![0; content_length];
+                let _ = reader.read_exact(&mut payload);
+                request.push_str("\r\n");
+                request.push_str(&String::from_utf8_lossy(&payload));
+                received.lock().unwrap().push(request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+        MockServer { url, requests }
+    }
+
+    /// Base URL of the server, like `http://127.0.0.1:4711`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The requests received so far: request line, headers, a blank line and the body.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_dir_is_removed() {
+        let dir = TempDir::new();
+        let path = dir.file("input.txt", "1,2");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2");
+
+        let root = dir.path().to_path_buf();
+        drop(dir);
+        assert!(!root.exists());
+    }
+
+    #[test]
+    fn test_mock_server() {
+        let server = MockServer::start(vec![(404, "not yet")]);
+        let mut stream = std::net::TcpStream::connect(server.url().trim_start_matches("http://")).unwrap();
+        write!(stream, "POST /x HTTP/1.1\r\nContent-Length: 3\r\n\r\na=1").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 404"));
+        assert!(response.ends_with("not yet"));
+        assert_eq!(server.requests(), vec!["POST /x HTTP/1.1\r\nContent-Length: 3\r\n\r\na=1"]);
+    }
+
+    #[test]
+    fn test_temp_dirs_are_distinct() {
+        assert_ne!(TempDir::new().path(), TempDir::new().path());
+    }
+}
@@ -0,0 +1,38 @@
+//! Embeds the hash of every day's sources, so the answer cache keys answers by
+//! the sources the runner was built from, not by those on disk when it runs.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+#[allow(dead_code)]
+#[path = "src/files.rs"]
+mod files;
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo"));
+    let workspace = manifest_dir.parent().expect("the runner is in the workspace");
+    let mut days: Vec<u8> = fs::read_dir(workspace)
+        .expect("the workspace can be read")
+        .flatten()
+        .filter(|entry| entry.path().join("src").is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.strip_prefix("day")?.parse().ok())
+        .collect();
+    days.sort();
+
+    // Registering a new day changes days.rs.
+    println!("cargo::rerun-if-changed=src/days.rs");
+    println!("cargo::rerun-if-changed=src/files.rs");
+    let mut out = String::from("pub const SOURCE_HASHES: &[(u8, &str)] = &[\n");
+    for day in days {
+        for path in files::source_paths(workspace, day) {
+            println!("cargo::rerun-if-changed={}", path.display());
+        }
+        let hash = files::source_hash(workspace, day).unwrap_or_else(|e| panic!("could not hash day {}: {}", day, e));
+        let _ = writeln!(out, "    ({}, \"{}\"),", day, hash);
+    }
+    out.push_str("];\n");
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(out_dir.join("source_hashes.rs"), out).expect("OUT_DIR is writable");
+}
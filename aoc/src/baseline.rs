@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_common::{LoadedInput, Params, Part, Stage, Timings};
+use serde::{Deserialize, Serialize};
+
+use crate::bench::median;
+use crate::days;
+use crate::report::input_hash;
+
+const STAGES: [Stage; 3] = [Stage::Parse, Stage::Solve(Part::One), Stage::Solve(Part::Two)];
+
+/// What a day was benchmarked on; timings are only compared when all of it matches.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Setup {
+    /// SHA-256 of every input, separated by commas when the parts have different ones.
+    pub input_hash: String,
+    pub params: String,
+    /// `debug` or `release`.
+    pub profile: String,
+}
+
+impl Setup {
+    /// The setup of a benchmark of `inputs` with `params` by this build of the runner.
+    pub fn new(inputs: &[LoadedInput], params: &Params) -> Setup {
+        let hashes: Vec<String> = inputs.iter().map(|input| input_hash(&input.contents)).collect();
+        let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+        Setup { input_hash: hashes.join(","), params: params.to_string(), profile: profile.to_string() }
+    }
+
+    /// What differs from `baseline`, or `None` if the timings can be compared.
+    pub fn differences(&self, baseline: &Setup) -> Option<String> {
+        let mut differences = Vec::new();
+        if self.input_hash != baseline.input_hash {
+            differences.push("another input".to_string());
+        }
+        if self.params != baseline.params {
+            differences.push(format!("parameters `{}`", baseline.params));
+        }
+        if self.profile != baseline.profile {
+            differences.push(format!("a {} build", baseline.profile));
+        }
+        (!differences.is_empty()).then(|| differences.join(", "))
+    }
+}
+
+/// Medians of one day, with what they were measured on.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct DayBaseline {
+    #[serde(flatten)]
+    setup: Setup,
+    #[serde(flatten)]
+    medians: BTreeMap<String, u64>,
+}
+
+/// Median timings saved from an earlier benchmark run on one machine.
+///
+/// Stored as TOML, in nanoseconds, with the setup they were measured on:
+///
+/// ```toml
+/// [day02]
+/// input_hash = "9f2c…"
+/// params = ""
+/// profile = "release"
+/// parse = 18250
+/// part1 = 4120000
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    days: BTreeMap<String, DayBaseline>,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn stage_key(stage: Stage) -> String {
+    match stage {
+        Stage::Parse => "parse".to_string(),
+        Stage::Solve(part) => format!("part{}", part),
+    }
+}
+
+/// Name of this machine: `AOC_MACHINE`, else the host name, else `default`.
+pub fn machine_name() -> String {
+    std::env::var("AOC_MACHINE")
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "default".to_string())
+}
+
+/// `bench-baselines/<machine>.toml` in the workspace.
+pub fn default_path() -> PathBuf {
+    days::workspace_dir()
+        .join("bench-baselines")
+        .join(format!("{}.toml", machine_name()))
+}
+
+impl Baseline {
+    pub fn parse(s: &str) -> Result<Baseline, String> {
+        toml::from_str(s).map(|days| Baseline { days }).map_err(|e| e.to_string())
+    }
+
+    /// Reads the baseline at `path`; `None` if there is none yet.
+    pub fn load(path: &Path) -> io::Result<Option<Baseline>> {
+        match fs::read_to_string(path) {
+            Ok(s) => Baseline::parse(&s)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let s = toml::to_string(&self.days).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+
+    /// Replaces the day's recorded medians with those of `timings`, measured on `setup`.
+    ///
+    /// Medians of stages that were not timed are kept if they were measured on the same setup.
+    pub fn record(&mut self, day: u8, timings: &Timings, setup: &Setup) {
+        let entry = self.days.entry(day_key(day)).or_default();
+        if entry.setup != *setup {
+            *entry = DayBaseline { setup: setup.clone(), medians: BTreeMap::new() };
+        }
+        for stage in STAGES {
+            if let Some(d) = median(timings, stage) {
+                entry.medians.insert(stage_key(stage), d.as_nanos() as u64);
+            }
+        }
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        let nanos = self.days.get(&day_key(day))?.medians.get(&stage_key(stage))?;
+        Some(Duration::from_nanos(*nanos))
+    }
+
+    /// Why `day` benchmarked on `setup` cannot be compared to its baseline, if it cannot.
+    pub fn mismatch(&self, day: u8, setup: &Setup) -> Option<String> {
+        let recorded = &self.days.get(&day_key(day))?.setup;
+        setup.differences(recorded).map(|differences| format!("the baseline was measured on {}", differences))
+    }
+
+    /// Stages of `day` that got more than `threshold` percent slower than recorded.
+    pub fn regressions(&self, day: u8, timings: &Timings, threshold: f64) -> Vec<Regression> {
+        STAGES
+            .into_iter()
+            .filter_map(|stage| {
+                let baseline = self.get(day, stage)?;
+                let current = median(timings, stage)?;
+                let regression = Regression { day, stage, baseline, current };
+                (regression.change() > threshold).then_some(regression)
+            })
+            .collect()
+    }
+}
+
+/// A stage whose median got slower than its baseline.
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// Change against the baseline, in percent.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64().max(f64::MIN_POSITIVE);
+        (self.current.as_secs_f64() / baseline - 1.0) * 100.0
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} {}: {:.2?}, baseline {:.2?} ({:+.1}%)",
+            self.day,
+            self.stage,
+            self.current,
+            self.baseline,
+            self.change()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Stats, TempDir};
+
+    fn timings(parse: u64, part1: u64) -> Timings {
+        let stats = |micros| {
+            let d = Duration::from_micros(micros);
+            Stats { mean: d, median: d, p95: d }
+        };
+        vec![(Stage::Parse, stats(parse)), (Stage::Solve(Part::One), stats(part1))]
+    }
+
+    fn setup(params: &str) -> Setup {
+        Setup { input_hash: "abc".to_string(), params: params.to_string(), profile: "release".to_string() }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = TempDir::new();
+        let path = dir.path().join("baselines").join("machine.toml");
+        assert_eq!(Baseline::load(&path).unwrap(), None);
+
+        let mut baseline = Baseline::default();
+        baseline.record(2, &timings(10, 200), &setup(""));
+        baseline.save(&path).unwrap();
+
+        let loaded = Baseline::load(&path).unwrap().unwrap();
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.get(2, Stage::Solve(Part::One)), Some(Duration::from_micros(200)));
+        assert_eq!(loaded.get(2, Stage::Solve(Part::Two)), None);
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("[day02]") && saved.contains("profile = \"release\""));
+    }
+
+    #[test]
+    fn test_regressions() {
+        let mut baseline = Baseline::default();
+        baseline.record(2, &timings(10, 200), &setup(""));
+
+        assert!(baseline.regressions(2, &timings(11, 150), 10.0).is_empty());
+        let regressions = baseline.regressions(2, &timings(10, 300), 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].stage, Stage::Solve(Part::One));
+        assert_eq!(regressions[0].to_string(), "Day 2 part 1: 300.00µs, baseline 200.00µs (+50.0%)");
+        assert!(baseline.regressions(3, &timings(10, 300), 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let mut baseline = Baseline::default();
+        baseline.record(8, &timings(10, 200), &setup("connections=1000"));
+        assert_eq!(baseline.mismatch(8, &setup("connections=1000")), None);
+        assert_eq!(baseline.mismatch(9, &setup("")), None);
+
+        let debug = Setup { input_hash: "def".to_string(), profile: "debug".to_string(), ..setup("connections=10") };
+        assert_eq!(
+            baseline.mismatch(8, &debug).unwrap(),
+            "the baseline was measured on another input, parameters `connections=1000`, a release build"
+        );
+    }
+
+    #[test]
+    fn test_record_on_another_setup_replaces_the_day() {
+        let mut baseline = Baseline::default();
+        baseline.record(8, &timings(10, 200), &setup("connections=1000"));
+        baseline.record(8, &vec![(Stage::Parse, timings(20, 0)[0].1)], &setup("connections=1000"));
+        assert_eq!(baseline.get(8, Stage::Solve(Part::One)), Some(Duration::from_micros(200)));
+        baseline.record(8, &vec![(Stage::Parse, timings(20, 0)[0].1)], &setup("connections=10"));
+        assert_eq!(baseline.get(8, Stage::Solve(Part::One)), None);
+        assert_eq!(baseline.get(8, Stage::Parse), Some(Duration::from_micros(20)));
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        assert!(Baseline::parse("[day02]\nparse = \"fast\"\n").is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use aoc_common::{Part, Stage, Timings};
+
+fn format_duration(d: Duration) -> String {
+    format!("{:.2?}", d)
+}
+
+/// Mean, median and p95 of every stage of one day.
+pub fn day_table(day: u8, timings: &Timings) -> String {
+    let mut out = format!("Day {}\n{:<8} {:>12} {:>12} {:>12}\n", day, "stage", "mean", "median", "p95");
+    for (stage, stats) in timings {
+        out.push_str(&format!(
+            "{:<8} {:>12} {:>12} {:>12}\n",
+            stage.to_string(),
+            format_duration(stats.mean),
+            format_duration(stats.median),
+            format_duration(stats.p95)
+        ));
+    }
+    out
+}
+
+/// Median of `stage`, summed when it ran for several inputs; `None` if it did not run.
+pub fn median(timings: &Timings, stage: Stage) -> Option<Duration> {
+    timings
+        .iter()
+        .filter(|(s, _)| *s == stage)
+        .map(|(_, stats)| stats.median)
+        .reduce(|a, b| a + b)
+}
+
+/// Median time of each stage for every day, with per-day and overall totals.
+pub fn summary_table(days: &[(u8, Timings)]) -> String {
+    let stages = [Stage::Parse, Stage::Solve(Part::One), Stage::Solve(Part::Two)];
+    let mut out = format!("{:<5} {:>12} {:>12} {:>12} {:>12}\n", "day", "parse", "part 1", "part 2", "total");
+    let mut totals = [Duration::ZERO; 3];
+    for (day, timings) in days {
+        out.push_str(&format!("{:<5}", day));
+        for (i, &stage) in stages.iter().enumerate() {
+            let cell = match median(timings, stage) {
+                Some(d) => {
+                    totals[i] += d;
+                    format_duration(d)
+                }
+                None => "-".to_string(),
+            };
+            out.push_str(&format!(" {:>12}", cell));
+        }
+        let total = stages.iter().filter_map(|&stage| median(timings, stage)).sum();
+        out.push_str(&format!(" {:>12}\n", format_duration(total)));
+    }
+    out.push_str(&format!("{:<5}", "all"));
+    for total in totals {
+        out.push_str(&format!(" {:>12}", format_duration(total)));
+    }
+    out.push_str(&format!(" {:>12}\n", format_duration(totals.iter().sum())));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Stats;
+
+    fn stats(micros: u64) -> Stats {
+        let d = Duration::from_micros(micros);
+        Stats { mean: d, median: d, p95: d }
+    }
+
+    #[test]
+    fn test_day_table() {
+        let table = day_table(5, &vec![(Stage::Parse, stats(10)), (Stage::Solve(Part::One), stats(1500))]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "Day 5");
+        assert!(lines[2].starts_with("parse") && lines[2].ends_with("10.00µs"));
+        assert!(lines[3].starts_with("part 1") && lines[3].ends_with("1.50ms"));
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let days = vec![
+            (1, vec![(Stage::Parse, stats(1)), (Stage::Solve(Part::One), stats(2)), (Stage::Parse, stats(1))]),
+            (2, vec![(Stage::Parse, stats(4)), (Stage::Solve(Part::Two), stats(8))]),
+        ];
+        let table = summary_table(&days);
+        let rows: Vec<Vec<&str>> = table.lines().map(|l| l.split_whitespace().collect()).collect();
+        assert_eq!(rows[1], vec!["1", "2.00µs", "2.00µs", "-", "4.00µs"]);
+        assert_eq!(rows[2], vec!["2", "4.00µs", "-", "8.00µs", "12.00µs"]);
+        assert_eq!(rows[3], vec!["all", "6.00µs", "2.00µs", "8.00µs", "16.00µs"]);
+    }
+}
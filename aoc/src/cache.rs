@@ -0,0 +1,151 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Answer, Params, Part};
+use serde::{Deserialize, Serialize};
+
+// `SOURCE_HASHES`: the source hash of every day, from the build script.
+include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+/// Cached answers of `day`: `.aoc-cache/answers/dayNN.toml`.
+pub fn cache_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join("answers").join(format!("day{:02}.toml", day))
+}
+
+/// What the answers of a day are computed by: its sources, with aoc-common's,
+/// and the parameters it is solved with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Version {
+    pub source_hash: String,
+    pub params: String,
+}
+
+impl Version {
+    /// The version of `day` with `params`, from the sources the runner was built
+    /// from: a runner that was not rebuilt after a change keeps its own answers
+    /// apart from the changed day's. `None` if the runner was built without the day.
+    pub fn of(day: u8, params: &Params) -> Option<Version> {
+        let (_, source_hash) = SOURCE_HASHES.iter().find(|(d, _)| *d == day)?;
+        Some(Version { source_hash: source_hash.to_string(), params: params.to_string() })
+    }
+}
+
+/// One answer, with everything that decides it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub part: u8,
+    pub input_hash: String,
+    pub source_hash: String,
+    pub params: String,
+    pub answer: Answer,
+}
+
+/// Answers computed before, so unchanged days need not be solved again.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnswerCache {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Entry>,
+}
+
+impl AnswerCache {
+    pub fn load(path: &Path) -> io::Result<AnswerCache> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(AnswerCache::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let s = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+
+    /// The answer `version` gave for `part` of the input with `input_hash`, if it was recorded.
+    pub fn get(&self, version: &Version, input_hash: &str, part: Part) -> Option<&Answer> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry.part == part.number()
+                    && entry.input_hash == input_hash
+                    && entry.source_hash == version.source_hash
+                    && entry.params == version.params
+            })
+            .map(|entry| &entry.answer)
+    }
+
+    /// Records an answer of `version`, dropping the answers of other sources, which
+    /// no longer match the day unless its changes are undone.
+    pub fn record(&mut self, version: &Version, input_hash: &str, part: Part, answer: Answer) {
+        self.entries.retain(|entry| {
+            entry.source_hash == version.source_hash
+                && !(entry.part == part.number() && entry.input_hash == input_hash && entry.params == version.params)
+        });
+        self.entries.push(Entry {
+            part: part.number(),
+            input_hash: input_hash.to_string(),
+            source_hash: version.source_hash.clone(),
+            params: version.params.clone(),
+            answer,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TempDir;
+
+    fn version(source_hash: &str, params: &str) -> Version {
+        Version { source_hash: source_hash.to_string(), params: params.to_string() }
+    }
+
+    #[test]
+    fn test_get_and_record() {
+        let mut cache = AnswerCache::default();
+        let v1 = version("aaa", "connections=1000");
+        cache.record(&v1, "input", Part::One, Answer::Int(17095));
+        cache.record(&v1, "input", Part::Two, Answer::from("abc"));
+        cache.record(&v1, "example", Part::One, Answer::Int(40));
+
+        assert_eq!(cache.get(&v1, "input", Part::One), Some(&Answer::Int(17095)));
+        assert_eq!(cache.get(&v1, "input", Part::Two), Some(&Answer::from("abc")));
+        assert_eq!(cache.get(&v1, "other", Part::One), None);
+        assert_eq!(cache.get(&version("aaa", "connections=10"), "input", Part::One), None);
+
+        cache.record(&v1, "input", Part::One, Answer::Int(17096));
+        assert_eq!(cache.get(&v1, "input", Part::One), Some(&Answer::Int(17096)));
+        assert_eq!(cache.entries.len(), 3);
+
+        let v2 = version("bbb", "connections=1000");
+        assert_eq!(cache.get(&v2, "input", Part::One), None);
+        cache.record(&v2, "input", Part::One, Answer::Int(1));
+        assert_eq!(cache.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = TempDir::new();
+        let path = cache_path(dir.path(), 8);
+        assert_eq!(AnswerCache::load(&path).unwrap(), AnswerCache::default());
+        let mut cache = AnswerCache::default();
+        cache.record(&version("aaa", ""), "input", Part::Two, Answer::from(u128::MAX));
+        cache.save(&path).unwrap();
+        assert_eq!(AnswerCache::load(&path).unwrap(), cache);
+    }
+
+    #[test]
+    fn test_version() {
+        let params = Params::defaults(&[("part1_batteries", 2, 1..=19), ("part2_batteries", 12, 1..=19)]);
+        let version = Version::of(3, &params).unwrap();
+        assert_eq!(version.params, "part1_batteries=2,part2_batteries=12");
+        assert_eq!(version.source_hash, crate::files::source_hash(&crate::days::workspace_dir(), 3).unwrap());
+        assert_ne!(Version::of(4, &params).unwrap().source_hash, version.source_hash);
+        assert_eq!(Version::of(25, &params), None);
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Root of the workspace the runner was built in.
+pub fn workspace_dir() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
+
+/// Directory of the day's crate, e.g. `day05`.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day{:02}", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_sorted_and_unique() {
+        for pair in DAYS.windows(2) {
+            assert!(pair[0].day < pair[1].day);
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(5).unwrap().day, 5);
+        assert!(find(25).is_none());
+    }
+
+    #[test]
+    fn test_day_dir() {
+        assert!(day_dir(3).ends_with("day03"));
+    }
+}
@@ -0,0 +1,240 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Answer, KnownAnswers, Part};
+
+use crate::html;
+
+/// What the description of one part shows: its example inputs and the answer it highlights.
+#[derive(Debug, Default, PartialEq)]
+pub struct Description {
+    /// Every `<pre><code>` block, in order; the first one is usually the example input.
+    pub examples: Vec<String>,
+    /// The last `<code><em>` in the text, which is where the example's answer is given.
+    pub answer: Option<Answer>,
+}
+
+/// Byte offset of `inner`, a slice of `outer`, within `outer`.
+fn offset(outer: &str, inner: &str) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
+
+fn describe(article: &str) -> Description {
+    let examples = html::elements(article, "pre")
+        .into_iter()
+        .filter_map(|pre| html::elements(pre, "code").first().map(|code| html::text(code)))
+        .map(|example| if example.ends_with('\n') { example } else { example + "\n" })
+        .collect();
+
+    // Answers are emphasized code, written either `<code><em>42</em></code>` or `<em><code>42</code></em>`.
+    let is_wrapped = |s: &str, tag: &str| {
+        let s = s.trim();
+        s.starts_with(&format!("<{}>", tag)) && s.ends_with(&format!("</{}>", tag)) && html::elements(s, tag).len() == 1
+    };
+    let code_em = html::elements(article, "code").into_iter().filter(|code| is_wrapped(code, "em"));
+    let em_code = html::elements(article, "em").into_iter().filter(|em| is_wrapped(em, "code"));
+    let answer = code_em
+        .chain(em_code)
+        .max_by_key(|highlight| offset(article, highlight))
+        .map(html::text)
+        .filter(|answer| !answer.trim().is_empty())
+        .map(Answer::from);
+    Description { examples, answer }
+}
+
+/// The descriptions on a puzzle page: part 1, and part 2 once it is unlocked.
+pub fn parse_page(page: &str) -> Vec<Description> {
+    html::elements(page, "article").into_iter().take(2).map(describe).collect()
+}
+
+fn toml_value(answer: &Answer) -> String {
+    let value = match answer.as_int().map(i64::try_from) {
+        Some(Ok(n)) => toml::Value::Integer(n),
+        _ => toml::Value::String(answer.to_string()),
+    };
+    value.to_string()
+}
+
+/// Adds the example answers that `answers_toml` does not have yet, keeping everything else as it is.
+fn add_example_answers(answers_toml: &str, answers: &[(Part, Answer)]) -> Result<(String, Vec<Part>), String> {
+    let known = KnownAnswers::parse(answers_toml)?;
+    let new: Vec<&(Part, Answer)> = answers.iter().filter(|(part, _)| known.example.get(*part).is_none()).collect();
+    if new.is_empty() {
+        return Ok((answers_toml.to_string(), Vec::new()));
+    }
+    let entries: Vec<String> = new.iter().map(|(part, answer)| format!("part{} = {}", part, toml_value(answer))).collect();
+    let entries: Vec<&str> = entries.iter().map(String::as_str).collect();
+
+    let mut lines: Vec<&str> = answers_toml.lines().collect();
+    if let Some(header) = lines.iter().position(|l| l.trim() == "[example]") {
+        let end = lines[header + 1..]
+            .iter()
+            .position(|l| l.trim_start().starts_with('['))
+            .map_or(lines.len(), |i| header + 1 + i);
+        let mut at = end;
+        while at > header + 1 && lines[at - 1].trim().is_empty() {
+            at -= 1;
+        }
+        lines.splice(at..at, entries);
+    } else {
+        let mut table = vec!["[example]"];
+        table.extend(entries);
+        match lines.iter().position(|l| l.trim() == "[input]") {
+            Some(at) => {
+                table.push("");
+                lines.splice(at..at, table);
+            }
+            None => {
+                if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                    lines.push("");
+                }
+                lines.extend(table);
+            }
+        }
+    }
+    Ok((lines.join("\n") + "\n", new.iter().map(|(part, _)| *part).collect()))
+}
+
+/// What [`install`] changed in a day's directory.
+#[derive(Debug, Default, PartialEq)]
+pub struct Installed {
+    pub files: Vec<PathBuf>,
+    pub answers: Vec<(Part, Answer)>,
+}
+
+fn is_empty_or_missing(path: &Path) -> bool {
+    fs::read_to_string(path).map_or(true, |s| s.trim().is_empty())
+}
+
+/// Writes the examples of the page into `test-part1.txt` and, if part 2 has a different one,
+/// `test-part2.txt`, and their answers into `answers.toml`.
+///
+/// Example files that already have contents and answers that are already known are left alone.
+pub fn install(day_dir: &Path, descriptions: &[Description]) -> io::Result<Installed> {
+    if !day_dir.is_dir() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", day_dir.display())));
+    }
+    let Some(part1) = descriptions.first() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "the page has no puzzle description"));
+    };
+    let example1 = part1.examples.first();
+    let example2 = descriptions.get(1).and_then(|part2| part2.examples.first()).filter(|&e| Some(e) != example1);
+
+    let mut installed = Installed::default();
+    for (file, example) in [("test-part1.txt", example1), ("test-part2.txt", example2)] {
+        let path = day_dir.join(file);
+        if let Some(example) = example
+            && is_empty_or_missing(&path)
+        {
+            fs::write(&path, example)?;
+            installed.files.push(path);
+        }
+    }
+
+    let answers: Vec<(Part, Answer)> = Part::ALL
+        .into_iter()
+        .zip(descriptions)
+        .filter_map(|(part, description)| description.answer.clone().map(|answer| (part, answer)))
+        .collect();
+    let path = day_dir.join("answers.toml");
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    let (edited, added) = add_example_answers(&text, &answers)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))?;
+    if !added.is_empty() {
+        fs::write(&path, edited)?;
+        installed.answers = answers.into_iter().filter(|(part, _)| added.contains(part)).collect();
+    }
+    Ok(installed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TempDir;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Cafeteria ---</h2><p>For example:</p>
+<pre><code>3-5
+10-14
+
+1
+5
+</code></pre>
+<p>Ingredient ID <code>5</code> is <em>fresh</em>.</p>
+<p>In this example, <code><em>3</em></code> of the available ingredient IDs are fresh.</p>
+</article>
+<p>Your puzzle answer was <code>690</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>3-5
+10-14
+</code></pre>
+<p>So, the fresh ingredient ID ranges consider a total of <em><code>14</code></em> ingredient IDs &amp; more.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_page() {
+        let descriptions = parse_page(PAGE);
+        assert_eq!(descriptions.len(), 2);
+        assert_eq!(descriptions[0].examples, vec!["3-5\n10-14\n\n1\n5\n"]);
+        assert_eq!(descriptions[0].answer, Some(Answer::Int(3)));
+        assert_eq!(descriptions[1].examples, vec!["3-5\n10-14\n"]);
+        assert_eq!(descriptions[1].answer, Some(Answer::Int(14)));
+
+        let escaped = "<article><pre><code>a &lt;-&gt; <em>b</em></code></pre></article>";
+        assert_eq!(parse_page(escaped)[0], Description { examples: vec!["a <-> b\n".to_string()], answer: None });
+    }
+
+    #[test]
+    fn test_add_example_answers() {
+        let answers = [(Part::One, Answer::Int(3)), (Part::Two, Answer::from("x,y"))];
+
+        let template = "# Known answers\n#\n# [example]\n# part1 = 0\n";
+        let (edited, added) = add_example_answers(template, &answers).unwrap();
+        assert_eq!(edited, "# Known answers\n#\n# [example]\n# part1 = 0\n\n[example]\npart1 = 3\npart2 = \"x,y\"\n");
+        assert_eq!(added, vec![Part::One, Part::Two]);
+
+        let partial = "[example]\npart1 = 4\n\n[input]\npart1 = 690\n";
+        let (edited, added) = add_example_answers(partial, &answers).unwrap();
+        assert_eq!(edited, "[example]\npart1 = 4\npart2 = \"x,y\"\n\n[input]\npart1 = 690\n");
+        assert_eq!(added, vec![Part::Two]);
+
+        let (edited, _) = add_example_answers("[input]\npart1 = 690\n", &answers[..1]).unwrap();
+        assert_eq!(edited, "[example]\npart1 = 3\n\n[input]\npart1 = 690\n");
+        assert!(add_example_answers("[examples]\n", &answers).is_err());
+    }
+
+    #[test]
+    fn test_toml_value() {
+        for text in ["x,y", "say \"hi\"", "C:\\path", "tab\there", "ünï©ødé ★", "\u{7f}\u{1b}"] {
+            let answer = Answer::from(text);
+            let (edited, _) = add_example_answers("", &[(Part::One, answer.clone())]).unwrap();
+            assert_eq!(KnownAnswers::parse(&edited).unwrap().example.part1, Some(answer), "{}", edited);
+        }
+        assert_eq!(toml_value(&Answer::Int(-3)), "-3");
+        assert_eq!(toml_value(&Answer::Int(u64::MAX as i128)), "\"18446744073709551615\"");
+    }
+
+    #[test]
+    fn test_install() {
+        let dir = TempDir::new();
+        dir.file("test-part1.txt", "");
+        dir.file("answers.toml", "[input]\npart1 = 690\n");
+
+        let installed = install(dir.path(), &parse_page(PAGE)).unwrap();
+        assert_eq!(installed.files, vec![dir.path().join("test-part1.txt"), dir.path().join("test-part2.txt")]);
+        assert_eq!(installed.answers, vec![(Part::One, Answer::Int(3)), (Part::Two, Answer::Int(14))]);
+        assert_eq!(fs::read_to_string(dir.path().join("test-part1.txt")).unwrap(), "3-5\n10-14\n\n1\n5\n");
+        let known = KnownAnswers::read(dir.path()).unwrap();
+        assert_eq!((known.example.part1, known.example.part2), (Some(Answer::Int(3)), Some(Answer::Int(14))));
+
+        // A second run changes nothing.
+        assert_eq!(install(dir.path(), &parse_page(PAGE)).unwrap(), Installed::default());
+        assert!(install(&dir.path().join("day05"), &parse_page(PAGE)).is_err());
+    }
+}
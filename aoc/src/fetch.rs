@@ -0,0 +1,155 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::encrypted::encrypted_path;
+
+use crate::web::{WebError, YEAR};
+
+/// Gitignored directory, in the workspace, that keeps everything downloaded.
+pub const CACHE_DIR: &str = ".aoc-cache";
+
+/// Cached input of `day`: `.aoc-cache/inputs/2025/dayNN.txt`.
+pub fn cache_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join("inputs").join(YEAR.to_string()).join(format!("day{:02}.txt", day))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FetchError {
+    Web(WebError),
+    Io(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::Web(e) => write!(f, "{}", e),
+            FetchError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> FetchError {
+    FetchError::Io(format!("could not {} {}: {}", action, path.display(), e))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// Read from the cache; nothing was downloaded.
+    Cached,
+    /// Already in the day directory, as `input.txt` or `input.txt.enc`; nothing was downloaded.
+    Installed,
+    Downloaded,
+}
+
+fn non_empty(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Makes sure `dest` holds the input of `day`, downloading it only if it is not cached yet.
+///
+/// An existing, non-empty `dest`, or its encrypted copy, counts as cached and is left
+/// alone; a plain `dest` seeds the cache when it has no entry for `day`.
+pub fn fetch_input(
+    cache_dir: &Path,
+    day: u8,
+    dest: &Path,
+    download: impl FnOnce(u8) -> Result<String, WebError>,
+) -> Result<Fetched, FetchError> {
+    let cached = cache_path(cache_dir, day);
+    if non_empty(dest) {
+        if !cached.exists() {
+            if let Some(dir) = cached.parent() {
+                fs::create_dir_all(dir).map_err(|e| io_error("create", dir, e))?;
+            }
+            fs::copy(dest, &cached).map_err(|e| io_error("write", &cached, e))?;
+        }
+        return Ok(Fetched::Installed);
+    }
+    if non_empty(&encrypted_path(dest)) {
+        return Ok(Fetched::Installed);
+    }
+    let (input, fetched) = match fs::read_to_string(&cached) {
+        Ok(input) => (input, Fetched::Cached),
+        Err(_) => {
+            let input = download(day).map_err(FetchError::Web)?;
+            if input.trim().is_empty() {
+                return Err(FetchError::Io("the downloaded input is empty".to_string()));
+            }
+            if let Some(dir) = cached.parent() {
+                fs::create_dir_all(dir).map_err(|e| io_error("create", dir, e))?;
+            }
+            fs::write(&cached, &input).map_err(|e| io_error("write", &cached, e))?;
+            (input, Fetched::Downloaded)
+        }
+    };
+    if dest.parent().is_some_and(Path::exists) {
+        fs::write(dest, input).map_err(|e| io_error("write", dest, e))?;
+    }
+    Ok(fetched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{MockServer, TempDir};
+
+    use crate::web::Client;
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let dir = TempDir::new();
+        let cache = dir.path().join(CACHE_DIR);
+        let dest = dir.path().join("input.txt");
+        let server = MockServer::start(vec![(200, "1,2\n")]);
+        let client = Client::new(server.url(), "abc123");
+
+        assert_eq!(fetch_input(&cache, 9, &dest, |day| client.input(day)), Ok(Fetched::Downloaded));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "1,2\n");
+        assert_eq!(fs::read_to_string(cache_path(&cache, 9)).unwrap(), "1,2\n");
+
+        fs::remove_file(&dest).unwrap();
+        let result = fetch_input(&cache, 9, &dest, |_| panic!("cached days must not be downloaded"));
+        assert_eq!(result, Ok(Fetched::Cached));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "1,2\n");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_fetch_keeps_existing_input() {
+        let dir = TempDir::new();
+        let cache = dir.path().join(CACHE_DIR);
+        let dest = dir.file("input.txt", "mine\n");
+        let result = fetch_input(&cache, 9, &dest, |_| panic!("installed days must not be downloaded"));
+        assert_eq!(result, Ok(Fetched::Installed));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "mine\n");
+        assert_eq!(fs::read_to_string(cache_path(&cache, 9)).unwrap(), "mine\n");
+
+        fs::write(cache_path(&cache, 9), "cached\n").unwrap();
+        assert_eq!(fetch_input(&cache, 9, &dest, |_| panic!("installed days must not be downloaded")), Ok(Fetched::Installed));
+        assert_eq!(fs::read_to_string(cache_path(&cache, 9)).unwrap(), "cached\n");
+    }
+
+    #[test]
+    fn test_fetch_keeps_encrypted_input() {
+        let dir = TempDir::new();
+        let cache = dir.path().join(CACHE_DIR);
+        let dest = dir.path().join("input.txt");
+        dir.file("input.txt.enc", "sealed");
+        let result = fetch_input(&cache, 9, &dest, |_| panic!("installed days must not be downloaded"));
+        assert_eq!(result, Ok(Fetched::Installed));
+        assert!(!dest.exists() && !cache_path(&cache, 9).exists());
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dir = TempDir::new();
+        let cache = dir.path().join(CACHE_DIR);
+        let dest = dir.path().join("input.txt");
+
+        let err = fetch_input(&cache, 12, &dest, |day| Err(WebError::NotUnlocked(day))).unwrap_err();
+        assert_eq!(err, FetchError::Web(WebError::NotUnlocked(12)));
+        assert_eq!(err.to_string(), "day 12 is not unlocked yet");
+        assert!(!cache_path(&cache, 12).exists() && !dest.exists());
+    }
+}
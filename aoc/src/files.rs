@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use sha2::{Digest, Sha256};
+
+/// Modification time and size of every file found.
+pub type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+fn visit(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else { return };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            visit(&entry.path(), snapshot);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_path_buf(), (modified, metadata.len()));
+    }
+}
+
+/// The files under `paths` as they are now; paths that do not exist are left out.
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        visit(path, &mut snapshot);
+    }
+    snapshot
+}
+
+/// What the answers of `day` are computed by: its sources, with aoc-common's.
+pub fn source_paths(workspace: &Path, day: u8) -> Vec<PathBuf> {
+    let day_dir = workspace.join(format!("day{:02}", day));
+    vec![day_dir.join("src"), day_dir.join("Cargo.toml"), workspace.join("aoc-common").join("src")]
+}
+
+/// SHA-256 of every file under the [`source_paths`] of `day`, with its path in `workspace`.
+pub fn source_hash(workspace: &Path, day: u8) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    for path in snapshot(&source_paths(workspace, day)).keys() {
+        let contents = fs::read(path)?;
+        hasher.update(path.strip_prefix(workspace).unwrap_or(path).to_string_lossy().as_bytes());
+        hasher.update((contents.len() as u64).to_le_bytes());
+        hasher.update(&contents);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TempDir;
+
+    #[test]
+    fn test_snapshot() {
+        let dir = TempDir::new();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        let lib = dir.file("src/lib.rs", "fn main() {}");
+        let snapshot = snapshot(&[dir.path().join("src"), dir.path().join("input.txt")]);
+        assert_eq!(snapshot.keys().collect::<Vec<_>>(), vec![&lib]);
+        assert_eq!(snapshot[&lib].1, 12);
+    }
+
+    #[test]
+    fn test_source_hash() {
+        let dir = TempDir::new();
+        fs::create_dir_all(dir.path().join("day03/src")).unwrap();
+        fs::create_dir_all(dir.path().join("aoc-common/src")).unwrap();
+        dir.file("day03/src/lib.rs", "pub fn solve() {}");
+        dir.file("aoc-common/src/lib.rs", "");
+
+        let before = source_hash(dir.path(), 3).unwrap();
+        assert_eq!(before.len(), 64);
+        assert_eq!(source_hash(dir.path(), 3).unwrap(), before);
+        dir.file("day03/input.txt", "987654321111111\n");
+        assert_eq!(source_hash(dir.path(), 3).unwrap(), before);
+        dir.file("aoc-common/src/lib.rs", "// changed");
+        assert_ne!(source_hash(dir.path(), 3).unwrap(), before);
+    }
+}
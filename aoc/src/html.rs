@@ -0,0 +1,94 @@
+//! Just enough HTML handling for the pages of adventofcode.com, which are
+//! regular enough not to need a real parser.
+
+/// Contents of every `<tag ...>...</tag>` in `html`, in order; elements of the same tag must not nest.
+pub fn elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(at) = rest.find(&open) {
+        let after = &rest[at + open.len()..];
+        // `<code` must not match `<codex>`.
+        if !after.starts_with(['>', ' ', '\t', '\n']) {
+            rest = after;
+            continue;
+        }
+        let Some(start) = after.find('>') else { break };
+        let inner = &after[start + 1..];
+        let Some(end) = inner.find(&close) else { break };
+        found.push(&inner[..end]);
+        rest = &inner[end + close.len()..];
+    }
+    found
+}
+
+/// `html` without its tags and with entities decoded; whitespace is kept as it is.
+pub fn text(html: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&stripped)
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = s;
+    while let Some(at) = rest.find('&') {
+        decoded.push_str(&rest[..at]);
+        rest = &rest[at..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let c = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => name.strip_prefix('#').and_then(|n| n.parse().ok()),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (c, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elements() {
+        let html = "<article class=\"day-desc\"><p>a</p></article><p>b</p><pre><code>1</code></pre><article>c</article>";
+        assert_eq!(elements(html, "article"), vec!["<p>a</p>", "c"]);
+        assert_eq!(elements(html, "p"), vec!["a", "b"]);
+        assert!(elements(html, "pr").is_empty());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text("<code>x &lt; <em>10</em> &amp;&amp; y&gt;2</code>"), "x < 10 && y>2");
+        assert_eq!(text("&#39;&#x41;&nbsp;& rest"), "'A&nbsp;& rest");
+    }
+}
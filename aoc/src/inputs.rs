@@ -0,0 +1,94 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::encrypted::{self, Key};
+
+/// What encrypting or decrypting the input of one day did.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Written(PathBuf),
+    /// The file already holds the same input.
+    Unchanged(PathBuf),
+    /// There is no input to encrypt or decrypt at this path.
+    Missing(PathBuf),
+}
+
+fn with_path(path: &Path) -> impl FnOnce(io::Error) -> io::Error + '_ {
+    move |e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+}
+
+fn read_if_exists(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(s) => Ok(Some(s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(with_path(path)(e)),
+    }
+}
+
+/// Encrypts `input.txt` of `day_dir` into `input.txt.enc`.
+pub fn encrypt(day_dir: &Path, key: &Key) -> io::Result<Outcome> {
+    let path = day_dir.join("input.txt");
+    let encrypted = encrypted::encrypted_path(&path);
+    let Some(input) = read_if_exists(&path)? else { return Ok(Outcome::Missing(path)) };
+    // Every encryption gives a different file, so an input that did not change keeps its old one.
+    if let Some(existing) = read_if_exists(&encrypted)?
+        && encrypted::decrypt(&existing, key).is_ok_and(|decrypted| decrypted == input)
+    {
+        return Ok(Outcome::Unchanged(encrypted));
+    }
+    fs::write(&encrypted, encrypted::encrypt(&input, key)?).map_err(with_path(&encrypted))?;
+    Ok(Outcome::Written(encrypted))
+}
+
+/// Decrypts `input.txt.enc` of `day_dir` into `input.txt`, without replacing a different input.
+pub fn decrypt(day_dir: &Path, key: &Key) -> io::Result<Outcome> {
+    let path = day_dir.join("input.txt");
+    let encrypted = encrypted::encrypted_path(&path);
+    let Some(contents) = read_if_exists(&encrypted)? else { return Ok(Outcome::Missing(encrypted)) };
+    let input = encrypted::decrypt(&contents, key).map_err(with_path(&encrypted))?;
+    match read_if_exists(&path)? {
+        Some(existing) if existing == input => return Ok(Outcome::Unchanged(path)),
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} differs from {}; remove it to decrypt", path.display(), encrypted.display()),
+            ));
+        }
+        None => {}
+    }
+    fs::write(&path, input).map_err(with_path(&path))?;
+    Ok(Outcome::Written(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TempDir;
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let dir = TempDir::new();
+        let key = Key::passphrase("s3cret");
+        let input = dir.path().join("input.txt");
+        let encrypted = dir.path().join("input.txt.enc");
+        assert_eq!(encrypt(dir.path(), &key).unwrap(), Outcome::Missing(input.clone()));
+        assert_eq!(decrypt(dir.path(), &key).unwrap(), Outcome::Missing(encrypted.clone()));
+
+        dir.file("input.txt", "3-5\n");
+        assert_eq!(encrypt(dir.path(), &key).unwrap(), Outcome::Written(encrypted.clone()));
+        let first = fs::read_to_string(&encrypted).unwrap();
+        assert_eq!(encrypt(dir.path(), &key).unwrap(), Outcome::Unchanged(encrypted.clone()));
+        assert_eq!(fs::read_to_string(&encrypted).unwrap(), first);
+
+        assert_eq!(decrypt(dir.path(), &key).unwrap(), Outcome::Unchanged(input.clone()));
+        fs::remove_file(&input).unwrap();
+        assert_eq!(decrypt(dir.path(), &key).unwrap(), Outcome::Written(input.clone()));
+        assert_eq!(fs::read_to_string(&input).unwrap(), "3-5\n");
+
+        dir.file("input.txt", "3-6\n");
+        assert!(decrypt(dir.path(), &key).unwrap_err().to_string().contains("differs"));
+        assert!(decrypt(dir.path(), &Key::passphrase("guess")).is_err());
+        assert_eq!(encrypt(dir.path(), &key).unwrap(), Outcome::Written(encrypted));
+    }
+}
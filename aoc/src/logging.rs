@@ -0,0 +1,43 @@
+use std::io::{self, IsTerminal};
+
+use tracing::level_filters::LevelFilter;
+
+/// Global flags that turn on the solvers' tracing, written to stderr.
+#[derive(clap::Args, Debug)]
+pub struct LogArgs {
+    /// Log what the solvers compute, at debug level
+    #[arg(long, short, global = true)]
+    pub verbose: bool,
+    /// Log every step of the solvers; implies --verbose
+    #[arg(long, global = true)]
+    pub trace: bool,
+    /// Write log events as JSON lines instead of text
+    #[arg(long, global = true)]
+    pub log_json: bool,
+}
+
+impl LogArgs {
+    pub fn level(&self) -> LevelFilter {
+        if self.trace {
+            LevelFilter::TRACE
+        } else if self.verbose {
+            LevelFilter::DEBUG
+        } else {
+            LevelFilter::WARN
+        }
+    }
+
+    /// Installs the subscriber for the whole process; call once, before solving anything.
+    pub fn init(&self) {
+        let builder = tracing_subscriber::fmt()
+            .with_max_level(self.level())
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .with_target(false);
+        if self.log_json {
+            builder.json().with_current_span(true).with_span_list(false).init();
+        } else {
+            builder.init();
+        }
+    }
+}
@@ -0,0 +1,1053 @@
+mod baseline;
+mod bench;
+mod cache;
+mod days;
+mod examples;
+mod fetch;
+mod files;
+mod html;
+mod inputs;
+mod logging;
+mod pool;
+mod report;
+mod scaffold;
+mod submit;
+mod watch;
+mod web;
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_common::encrypted::Key;
+use aoc_common::{BenchConfig, Config, Day, InputSource, KnownAnswers, LoadedInput, Params, Part, SolveError, Timings, CONFIG_FILE};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    log: logging::LogArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or `all` of them
+    Run {
+        day: DaySelector,
+        /// Only solve this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        /// Print answers as text, or as JSON with timings and input hashes
+        #[arg(long, value_enum, default_value_t = report::Format::Text)]
+        format: report::Format,
+        /// Days solved at the same time [default: one per core]
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+        /// Solve again even if the answers are cached for these sources and inputs
+        #[arg(long)]
+        force: bool,
+    },
+    /// Time parsing and each part of one day, or `all` of them
+    Bench {
+        day: DaySelector,
+        /// Only time this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Untimed runs before sampling each stage
+        #[arg(long, default_value_t = 3)]
+        warmup: u32,
+        /// Timed runs of each stage
+        #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+        samples: u32,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        #[command(flatten)]
+        baseline: BaselineArgs,
+    },
+    /// Rebuild and solve one day again whenever its sources or inputs change
+    Watch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Write a random input for one day, to stress test or benchmark it on larger inputs
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// How large to make the input; what it counts depends on the day, like lines or grid width
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        /// Seed to generate the same input again [default: random, printed to stderr]
+        #[arg(long)]
+        seed: Option<u64>,
+        /// File to write the input to instead of stdout
+        #[arg(long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Download the puzzle input of one day, or `all` registered days, into dayNN/input.txt
+    Fetch {
+        day: DaySelector,
+    },
+    /// Keep the puzzle inputs encrypted, so they can be committed
+    Inputs {
+        #[command(subcommand)]
+        action: InputsAction,
+    },
+    /// Copy the examples and their answers from the puzzle page into dayNN
+    Examples {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Read a saved puzzle page instead of downloading it
+        #[arg(long, value_name = "PATH")]
+        page: Option<PathBuf>,
+    },
+    /// Solve a part on dayNN/input.txt and submit the answer
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Create dayNN from the dayXX template and register it
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Shape of the input the parser starts out with
+        #[arg(long, value_enum, default_value_t = scaffold::Template::Lines)]
+        template: scaffold::Template,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputsAction {
+    /// Encrypt dayNN/input.txt into dayNN/input.txt.enc
+    Encrypt {
+        day: DaySelector,
+        #[command(flatten)]
+        key: KeyArgs,
+    },
+    /// Decrypt dayNN/input.txt.enc into dayNN/input.txt
+    Decrypt {
+        day: DaySelector,
+        #[command(flatten)]
+        key: KeyArgs,
+    },
+}
+
+#[derive(clap::Args)]
+struct KeyArgs {
+    /// Key file to use instead of AOC_INPUTS_PASSPHRASE, AOC_INPUTS_KEY_FILE or .aoc-inputs-key
+    #[arg(long, value_name = "PATH")]
+    key_file: Option<PathBuf>,
+}
+
+impl KeyArgs {
+    fn key(&self, workspace: &Path) -> std::io::Result<Key> {
+        match &self.key_file {
+            Some(path) => Key::read_file(path),
+            None => Key::from_env(workspace),
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct BaselineArgs {
+    /// Save the medians as this machine's baseline
+    #[arg(long)]
+    save_baseline: bool,
+    /// Report stages that got slower than this machine's baseline
+    #[arg(long)]
+    compare: bool,
+    /// Baseline file to use instead of bench-baselines/<machine>.toml
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+    /// Slowdown against the baseline, in percent, that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+    /// Exit with failure on regressions instead of only warning
+    #[arg(long, requires = "compare")]
+    fail_on_regression: bool,
+}
+
+impl BaselineArgs {
+    /// Compares and saves as requested; returns false if a regression should fail the run.
+    ///
+    /// Days benchmarked on another input, other parameters or another build profile
+    /// than their baseline are not compared.
+    fn apply(&self, benched: &[Benched]) -> Result<bool, String> {
+        let path = self.baseline.clone().unwrap_or_else(baseline::default_path);
+        let existing = baseline::Baseline::load(&path).map_err(|e| format!("could not read baseline: {}", e))?;
+        let mut passed = true;
+        if self.compare {
+            let Some(existing) = &existing else {
+                return Err(format!("no baseline at {}, save one with --save-baseline", path.display()));
+            };
+            let mut regressions = Vec::new();
+            let mut compared = 0;
+            for (day, timings, setup) in benched {
+                match existing.mismatch(*day, setup) {
+                    Some(why) => println!("Day {}: not compared, {}", day, why),
+                    None => {
+                        compared += 1;
+                        regressions.extend(existing.regressions(*day, timings, self.threshold));
+                    }
+                }
+            }
+            if compared == 0 {
+                println!("No day was compared to {}", path.display());
+            } else if regressions.is_empty() {
+                println!("No stage is more than {}% slower than {}", self.threshold, path.display());
+            } else {
+                let label = if self.fail_on_regression { "error" } else { "warning" };
+                eprintln!("{}: slower than {} by more than {}%:", label, path.display(), self.threshold);
+                for regression in &regressions {
+                    eprintln!("  {}", regression);
+                }
+                passed = !self.fail_on_regression;
+            }
+        }
+        if self.save_baseline {
+            let mut updated = existing.unwrap_or_default();
+            for (day, timings, setup) in benched {
+                updated.record(*day, timings, setup);
+            }
+            updated.save(&path).map_err(|e| format!("could not save baseline: {}", e))?;
+            println!("Saved baseline to {}", path.display());
+        }
+        Ok(passed)
+    }
+}
+
+#[derive(clap::Args)]
+struct InputArgs {
+    /// Read the puzzle input from this file, or `-` for stdin
+    #[arg(long, value_name = "PATH", conflicts_with = "example")]
+    input: Option<String>,
+    /// Use the day's example input (test-part1.txt / test-part2.txt)
+    #[arg(long)]
+    example: bool,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        match self.input.as_deref() {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.into()),
+            None if self.example => InputSource::Example,
+            None => InputSource::Default,
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct ParamArgs {
+    /// Set a solver parameter, overriding aoc.toml; can be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+}
+
+fn parse_param(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s.split_once('=').ok_or_else(|| format!("expected NAME=VALUE, got `{}`", s))?;
+    let value = value.trim().parse().map_err(|_| format!("`{}` is not an integer", value.trim()))?;
+    Ok((name.trim().to_string(), value))
+}
+
+impl ParamArgs {
+    /// Parameters of each of `days` from `config`, with every `--param` applied to the days that declare it.
+    fn resolve(&self, config: &Config, days: &[&Day], source: &InputSource) -> Result<Vec<Params>, String> {
+        let mut resolved = days
+            .iter()
+            .map(|day| config.params(day.day, day.params, source))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (name, value) in &self.params {
+            if let [day] = days {
+                resolved[0].set(name, *value).map_err(|e| format!("--param {}: day {}: {}", name, day.day, e))?;
+                continue;
+            }
+            let mut declared = false;
+            for (day, params) in days.iter().zip(&mut resolved) {
+                if day.params.iter().any(|(declared_name, _, _)| declared_name == name) {
+                    params.set(name, *value).map_err(|e| format!("--param {}: day {}: {}", name, day.day, e))?;
+                    declared = true;
+                }
+            }
+            if !declared {
+                return Err(format!("--param {}: none of the selected days has this parameter", name));
+            }
+        }
+        Ok(resolved)
+    }
+}
+
+/// `aoc.toml` of the workspace.
+fn read_config() -> Result<Config, String> {
+    Config::read(&days::workspace_dir().join(CONFIG_FILE)).map_err(|e| e.to_string())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DaySelector {
+    All,
+    One(u8),
+}
+
+impl FromStr for DaySelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelector::All);
+        }
+        match s.parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(DaySelector::One(day)),
+            _ => Err(format!("expected a day between 1 and 25 or `all`, got `{}`", s)),
+        }
+    }
+}
+
+fn selected_days(selector: DaySelector) -> Result<Vec<&'static Day>, String> {
+    match selector {
+        DaySelector::All => Ok(days::DAYS.iter().collect()),
+        DaySelector::One(day) => days::find(day)
+            .map(|d| vec![d])
+            .ok_or_else(|| format!("Day {} is not solved yet", day)),
+    }
+}
+
+fn selected_parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// Why `input` could not be solved: the input is invalid, or a part has no answer for it.
+fn solve_failure(input: &LoadedInput, e: SolveError) -> String {
+    match e {
+        SolveError::Parse(_) => format!("invalid input\n{}", input.diagnostic(e).trim_end()),
+        SolveError::Part(..) => input.diagnostic(e).trim_end().to_string(),
+    }
+}
+
+/// Solves `day`, taking the answers from the answer cache when it has them all, unless `force` is set.
+fn run(day: &Day, parts: &[Part], source: &InputSource, params: &Params, force: bool) -> Result<Vec<report::PartRun>, String> {
+    let inputs = source
+        .read(&days::day_dir(day.day), parts)
+        .map_err(|e| format!("could not read input: {}", e))?;
+    let workspace = days::workspace_dir();
+    let path = cache::cache_path(&workspace.join(fetch::CACHE_DIR), day.day);
+    let version = cache::Version::of(day.day, params).ok_or("the runner was built without this day, rebuild it")?;
+    if files::source_hash(&workspace, day.day).is_ok_and(|hash| hash != version.source_hash) {
+        eprintln!("Day {}: the sources changed since the runner was built; rebuild it to solve with them", day.day);
+    }
+    // The cache only saves time, so one that cannot be read is started afresh.
+    let mut cache = cache::AnswerCache::load(&path).unwrap_or_else(|e| {
+        eprintln!("Day {}: ignoring the answer cache: {}", day.day, e);
+        cache::AnswerCache::default()
+    });
+    let mut solved_any = false;
+    let mut runs = Vec::new();
+    for input in inputs {
+        let hash = report::input_hash(&input.contents);
+        let cached: Option<aoc_common::Answers> = input
+            .parts
+            .iter()
+            .map(|&part| cache.get(&version, &hash, part).map(|answer| (part, answer.clone())))
+            .collect();
+        if let Some(answers) = cached.filter(|_| !force) {
+            runs.extend(report::PartRun::from_cache(day.day, &input, answers));
+            continue;
+        }
+        let solved = (day.solve_timed)(&input.contents, &input.parts, params)
+            .map_err(|e| solve_failure(&input, e))?;
+        for (part, answer, _) in &solved.answers {
+            cache.record(&version, &hash, *part, answer.clone());
+        }
+        solved_any = true;
+        runs.extend(report::PartRun::from_solved(day.day, &input, solved));
+    }
+    if solved_any && let Err(e) = cache.save(&path) {
+        eprintln!("Day {}: could not save the answer cache: {}", day.day, e);
+    }
+    Ok(runs)
+}
+
+/// A benchmarked day: its timings and what they were measured on.
+type Benched = (u8, Timings, baseline::Setup);
+
+/// Times every stage of `day`; `Ok(None)` if its input is missing and `skip_missing` is set.
+fn bench(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    params: &Params,
+    config: &BenchConfig,
+    skip_missing: bool,
+) -> Result<Option<Benched>, String> {
+    let day_dir = days::day_dir(day.day);
+    if skip_missing && let Some((path, why)) = source.missing(&day_dir, parts) {
+        println!("Day {}: skipped, {} {}\n", day.day, path.display(), why);
+        return Ok(None);
+    }
+    let inputs = source
+        .read(&day_dir, parts)
+        .map_err(|e| format!("could not read input: {}", e))?;
+    let mut timings = Vec::new();
+    for input in &inputs {
+        let timed = (day.bench)(&input.contents, &input.parts, params, config)
+            .map_err(|e| solve_failure(input, e))?;
+        timings.extend(timed);
+    }
+    Ok(Some((day.day, timings, baseline::Setup::new(&inputs, params))))
+}
+
+/// A selected day with the parameters to solve it with.
+type DayRun = (&'static Day, Params);
+
+/// Days for `day` with their parameters, and the source for `input`, or the message to exit with.
+fn selection(day: DaySelector, input: &InputArgs, params: &ParamArgs) -> Result<(Vec<DayRun>, InputSource), String> {
+    let source = input.source();
+    if day == DaySelector::All && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    let days = selected_days(day)?;
+    let params = params.resolve(&read_config()?, &days, &source)?;
+    Ok((days.into_iter().zip(params).collect(), source))
+}
+
+/// Solves `day` in a freshly built runner now and after every change, until interrupted.
+fn watch_day(day: u8, input: &InputArgs, params: &ParamArgs) -> ExitCode {
+    let workspace = days::workspace_dir();
+    let day_dir = days::day_dir(day);
+    if !day_dir.exists() {
+        eprintln!("day{:02} does not exist; create it with `aoc new {}`", day, day);
+        return ExitCode::FAILURE;
+    }
+    let source = input.source();
+    let mut paths = watch::watched_paths(&workspace, day);
+    let mut args = Vec::new();
+    match &source {
+        InputSource::Default => {}
+        InputSource::Example => args.push("--example".to_string()),
+        InputSource::File(path) => {
+            // The runner is rebuilt and run from the workspace, not from here.
+            let path = match std::path::absolute(path) {
+                Ok(path) => path,
+                Err(e) => {
+                    eprintln!("could not resolve {}: {}", path.display(), e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            args.extend(["--input".to_string(), path.display().to_string()]);
+            paths.push(path);
+        }
+        InputSource::Stdin => {
+            eprintln!("watch cannot read the input from stdin, it has to read it again after every change");
+            return ExitCode::FAILURE;
+        }
+    }
+    for (name, value) in &params.params {
+        args.extend(["--param".to_string(), format!("{}={}", name, value)]);
+    }
+
+    println!("Watching day{:02}, press Ctrl-C to stop", day);
+    let mut seen = files::snapshot(&paths);
+    loop {
+        match watch::rebuild_and_run(&workspace, day, &args) {
+            Ok(report) => {
+                // Read on every run, so answers recorded while watching count.
+                let known = KnownAnswers::read(&day_dir).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    KnownAnswers::default()
+                });
+                print!("{}", watch::summary(&report, known.for_source(&source)));
+            }
+            Err(e) => eprintln!("Day {}: {}", day, e),
+        }
+        let (next, files) = watch::wait_for_change(&paths, &seen);
+        seen = next;
+        let files: Vec<String> = files
+            .iter()
+            .map(|file| file.strip_prefix(&workspace).unwrap_or(file).display().to_string())
+            .collect();
+        println!("\nChanged {}", files.join(", "));
+    }
+}
+
+/// Installs the inputs of the selected days, downloading the ones that are not cached.
+fn fetch_inputs(selector: DaySelector) -> ExitCode {
+    let days: Vec<u8> = match selector {
+        DaySelector::All => days::DAYS.iter().map(|d| d.day).collect(),
+        DaySelector::One(day) => vec![day],
+    };
+    let root = days::workspace_dir();
+    let cache = root.join(fetch::CACHE_DIR);
+    let mut client: Option<web::Client> = None;
+    let mut failed = false;
+    for day in days {
+        let dest = days::day_dir(day).join("input.txt");
+        let download = |day| match &client {
+            Some(client) => client.input(day),
+            None => {
+                let created = client.insert(web::Client::from_env(&root)?);
+                created.input(day)
+            }
+        };
+        match fetch::fetch_input(&cache, day, &dest, download) {
+            Ok(fetched) => {
+                let how = match fetched {
+                    fetch::Fetched::Cached => "cached",
+                    fetch::Fetched::Installed => "already installed",
+                    fetch::Fetched::Downloaded => "downloaded",
+                };
+                if dest.parent().is_some_and(|dir| dir.exists()) {
+                    println!("Day {}: {}, {}", day, how, dest.display());
+                } else {
+                    println!("Day {}: {}, only kept in {} as day{:02} does not exist yet", day, how, fetch::CACHE_DIR, day);
+                }
+            }
+            Err(fetch::FetchError::Web(e @ (web::WebError::NoSession | web::WebError::SessionExpired))) => {
+                eprintln!("Day {}: {}", day, e);
+                return ExitCode::FAILURE;
+            }
+            Err(e @ fetch::FetchError::Web(web::WebError::NotUnlocked(_))) if selector == DaySelector::All => {
+                println!("Day {}: {}", day, e);
+            }
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                failed = true;
+            }
+        }
+    }
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Encrypts, or decrypts, the inputs of the selected days.
+fn convert_inputs(action: InputsAction) -> ExitCode {
+    let (selector, key, encrypt) = match action {
+        InputsAction::Encrypt { day, key } => (day, key, true),
+        InputsAction::Decrypt { day, key } => (day, key, false),
+    };
+    let key = match key.key(&days::workspace_dir()) {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let days: Vec<u8> = match selector {
+        DaySelector::All => days::DAYS.iter().map(|d| d.day).collect(),
+        DaySelector::One(day) => vec![day],
+    };
+    let mut failed = false;
+    for day in days {
+        let day_dir = days::day_dir(day);
+        let result = if encrypt { inputs::encrypt(&day_dir, &key) } else { inputs::decrypt(&day_dir, &key) };
+        match result {
+            Ok(inputs::Outcome::Written(path)) => println!("Day {}: wrote {}", day, path.display()),
+            Ok(inputs::Outcome::Unchanged(path)) => println!("Day {}: {} is up to date", day, path.display()),
+            Ok(inputs::Outcome::Missing(path)) if selector == DaySelector::All => {
+                println!("Day {}: skipped, {} does not exist", day, path.display());
+            }
+            Ok(inputs::Outcome::Missing(path)) => {
+                eprintln!("Day {}: {} does not exist", day, path.display());
+                failed = true;
+            }
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                failed = true;
+            }
+        }
+    }
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Writes a random input of `size` for `day`, from `seed` or a new seed.
+fn generate_input(day: u8, size: usize, seed: Option<u64>, output: Option<PathBuf>) -> ExitCode {
+    let Some(handle) = days::find(day) else {
+        eprintln!("Day {} is not solved yet", day);
+        return ExitCode::FAILURE;
+    };
+    let seed = seed.unwrap_or_else(|| {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+        let seed = nanos as u64 ^ (nanos >> 64) as u64;
+        eprintln!("Day {}: seed {}", day, seed);
+        seed
+    });
+    let Some(input) = (handle.generate)(size, seed) else {
+        eprintln!("Day {} has no input generator", day);
+        return ExitCode::FAILURE;
+    };
+    let written = match &output {
+        Some(path) => std::fs::write(path, &input).map_err(|e| format!("could not write {}: {}", path.display(), e)),
+        None => std::io::stdout().write_all(input.as_bytes()).map_err(|e| e.to_string()),
+    };
+    match written {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Installs the examples and example answers of the puzzle page of `day`.
+fn extract_examples(day: u8, page: Option<PathBuf>) -> ExitCode {
+    let html = match page {
+        Some(path) => std::fs::read_to_string(&path).map_err(|e| format!("could not read {}: {}", path.display(), e)),
+        None => web::Client::from_env(&days::workspace_dir())
+            .and_then(|client| client.page(day))
+            .map_err(|e| e.to_string()),
+    };
+    let html = match html {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match examples::install(&days::day_dir(day), &examples::parse_page(&html)) {
+        Ok(installed) => {
+            for file in &installed.files {
+                println!("  {}", file.display());
+            }
+            for (part, answer) in &installed.answers {
+                println!("  example answer for part {}: {}", part, answer);
+            }
+            if installed == examples::Installed::default() {
+                println!("Day {}: nothing new, existing examples and answers are kept", day);
+            } else {
+                println!("Day {}: check the extracted examples and answers before relying on them", day);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Solves `part` of `day` on its input and submits the answer, unless the history rules it out.
+fn submit_answer(day: u8, part: Part) -> ExitCode {
+    let Some(handle) = days::find(day) else {
+        eprintln!("Day {} is not solved yet", day);
+        return ExitCode::FAILURE;
+    };
+    let params = match read_config().and_then(|config| config.params(day, handle.params, &InputSource::Default)) {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = match InputSource::Default.read(&days::day_dir(day), &[part]) {
+        Ok(inputs) => match (handle.solve)(&inputs[0].contents, &[part], &params) {
+            Ok(answers) => answers[0].1.clone(),
+            Err(SolveError::Part(_, reason)) => {
+                eprintln!("Day {} part {}: not submitting, there is no answer: {}", day, part, reason);
+                return ExitCode::FAILURE;
+            }
+            Err(e) => {
+                eprintln!("Day {}: {}", day, solve_failure(&inputs[0], e));
+                return ExitCode::FAILURE;
+            }
+        },
+        Err(e) => {
+            eprintln!("Day {}: could not read input: {}", day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Day {} part {}: submitting {}", day, part, answer);
+
+    let root = days::workspace_dir();
+    let path = submit::history_path(&root.join(fetch::CACHE_DIR), day);
+    let mut history = match submit::History::load(&path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Could not read the submission history: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = match web::Client::from_env(&root) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = submit::submit(&client, &mut history, day, part, &answer, submit::now());
+    if let Err(e) = history.save(&path) {
+        eprintln!("Could not save the submission history: {}", e);
+    }
+    match result {
+        Ok(submit::Response::Answered { verdict: submit::Verdict::Right, .. }) => {
+            println!("That's the right answer!");
+            ExitCode::SUCCESS
+        }
+        Ok(submit::Response::Answered { verdict, wait }) => {
+            let wait = wait.map_or(String::new(), |w| format!("; wait {}s before the next answer", w.as_secs()));
+            println!("Wrong answer, {}{}", verdict, wait);
+            ExitCode::FAILURE
+        }
+        Ok(submit::Response::RateLimited(wait)) => {
+            println!("Answered too recently; try again in {}s", wait.as_secs());
+            ExitCode::FAILURE
+        }
+        Ok(submit::Response::WrongLevel) => {
+            println!("The site does not take answers for this part; is it already solved or still locked?");
+            ExitCode::FAILURE
+        }
+        Ok(submit::Response::Unknown(text)) => {
+            println!("Unexpected response: {}", text);
+            ExitCode::FAILURE
+        }
+        Err(submit::SubmitError::Refused(submit::Refusal::AlreadySolved(answer))) => {
+            println!("Already solved with {}", answer);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    cli.log.init();
+    match cli.command {
+        Command::Run { day: selector, part, input, params, format, jobs, force } => {
+            let (days, source) = match selection(selector, &input, &params) {
+                Ok(selection) => selection,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let parts = selected_parts(part);
+            let jobs = jobs.map_or_else(pool::default_jobs, |jobs| jobs as usize);
+            let mut report = report::Report::default();
+            // Like `bench all`, `run all` leaves out the days there is no input for yet.
+            let mut solvable = Vec::new();
+            for (day, params) in &days {
+                match source.missing(&days::day_dir(day.day), &parts).filter(|_| selector == DaySelector::All) {
+                    Some((path, why)) => {
+                        let reason = format!("{} {}", path.display(), why);
+                        eprintln!("Day {}: skipped, {}", day.day, reason);
+                        report.skipped.push(report::Skip { day: day.day, reason });
+                    }
+                    None => solvable.push((*day, params)),
+                }
+            }
+            let started = Instant::now();
+            let results = pool::map(&solvable, jobs, |(day, params)| run(day, &parts, &source, params, force));
+            report.elapsed_nanos = started.elapsed().as_nanos() as u64;
+            for ((day, _), result) in solvable.iter().zip(results) {
+                match result.unwrap_or_else(|panic| Err(format!("panicked: {}", panic))) {
+                    Ok(runs) => {
+                        if format == report::Format::Text && days.len() == 1 {
+                            println!("Day {}", day.day);
+                            for run in &runs {
+                                let cached = if run.cached { " (cached)" } else { "" };
+                                println!("Answer for part {}: {}{}", run.part, run.answer, cached);
+                            }
+                        }
+                        report.runs.extend(runs);
+                    }
+                    Err(e) => {
+                        eprintln!("Day {}: {}", day.day, e);
+                        report.failures.push(report::Failure { day: day.day, error: e });
+                    }
+                }
+            }
+            match format {
+                report::Format::Json => println!("{}", report.to_json()),
+                report::Format::Text if days.len() > 1 => {
+                    let numbers: Vec<u8> = days.iter().map(|(day, _)| day.day).collect();
+                    println!("{}", report.summary_table(&numbers));
+                }
+                report::Format::Text => {}
+            }
+            if report.failures.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        }
+        Command::Bench { day: selector, part, warmup, samples, input, params, baseline } => {
+            let (days, source) = match selection(selector, &input, &params) {
+                Ok(selection) => selection,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let parts = selected_parts(part);
+            let config = BenchConfig { warmup: warmup as usize, samples: samples as usize };
+            let mut benched = Vec::new();
+            let mut failed = false;
+            for (day, params) in days {
+                match bench(day, &parts, &source, &params, &config, selector == DaySelector::All) {
+                    Ok(Some(benched_day)) => {
+                        println!("{}", bench::day_table(day.day, &benched_day.1));
+                        benched.push(benched_day);
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        eprintln!("Day {}: {}", day.day, e);
+                        failed = true;
+                    }
+                }
+            }
+            if benched.len() > 1 {
+                let timings: Vec<(u8, Timings)> = benched.iter().map(|(day, timings, _)| (*day, timings.clone())).collect();
+                println!("{}", bench::summary_table(&timings));
+            }
+            match baseline.apply(&benched) {
+                Ok(passed) => failed |= !passed,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    failed = true;
+                }
+            }
+            if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        }
+        Command::Watch { day, input, params } => watch_day(day, &input, &params),
+        Command::Generate { day, size, seed, output } => generate_input(day, size, seed, output),
+        Command::Fetch { day } => fetch_inputs(day),
+        Command::Inputs { action } => convert_inputs(action),
+        Command::Examples { day, page } => extract_examples(day, page),
+        Command::Submit { day, part } => submit_answer(day, selected_parts(Some(part))[0]),
+        Command::New { day, template } => match scaffold::new_day(&days::workspace_dir(), day, template) {
+            Ok(files) => {
+                for file in files {
+                    println!("  {}", file.display());
+                }
+                println!("Created day{:02}; put the example in test-part1.txt and its answers in answers.toml", day);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("Could not create day {}: {}", day, e);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_selector() {
+        assert_eq!("all".parse::<DaySelector>(), Ok(DaySelector::All));
+        assert_eq!("5".parse::<DaySelector>(), Ok(DaySelector::One(5)));
+        assert!("0".parse::<DaySelector>().is_err());
+        assert!("26".parse::<DaySelector>().is_err());
+        assert!("five".parse::<DaySelector>().is_err());
+    }
+
+    #[test]
+    fn test_selected_days() {
+        assert_eq!(selected_days(DaySelector::All).unwrap().len(), days::DAYS.len());
+        assert_eq!(selected_days(DaySelector::One(3)).unwrap()[0].day, 3);
+        assert!(selected_days(DaySelector::One(24)).is_err());
+    }
+
+    #[test]
+    fn test_selected_parts() {
+        assert_eq!(selected_parts(None), vec![Part::One, Part::Two]);
+        assert_eq!(selected_parts(Some(2)), vec![Part::Two]);
+    }
+
+    #[test]
+    fn test_cli() {
+        let cli = Cli::try_parse_from(["aoc", "run", "3", "--part", "2"]).unwrap();
+        let Command::Run { day, part, input, format, .. } = cli.command else { panic!("expected run") };
+        assert_eq!(day, DaySelector::One(3));
+        assert_eq!(part, Some(2));
+        assert_eq!(input.source(), InputSource::Default);
+        assert_eq!(format, report::Format::Text);
+        let cli = Cli::try_parse_from(["aoc", "run", "all", "--format", "json", "--jobs", "2", "--force"]).unwrap();
+        assert!(matches!(cli.command, Command::Run { format: report::Format::Json, jobs: Some(2), force: true, .. }));
+        assert!(Cli::try_parse_from(["aoc", "run", "all", "--jobs", "0"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "3", "--format", "xml"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "3", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_logging() {
+        use tracing::level_filters::LevelFilter;
+
+        let level = |args: &[&str]| Cli::try_parse_from(args).unwrap().log.level();
+        assert_eq!(level(&["aoc", "run", "8"]), LevelFilter::WARN);
+        assert_eq!(level(&["aoc", "run", "8", "--verbose"]), LevelFilter::DEBUG);
+        assert_eq!(level(&["aoc", "-v", "bench", "8"]), LevelFilter::DEBUG);
+        assert_eq!(level(&["aoc", "run", "8", "--verbose", "--trace"]), LevelFilter::TRACE);
+        assert!(Cli::try_parse_from(["aoc", "run", "8", "--log-json"]).unwrap().log.log_json);
+    }
+
+    #[test]
+    fn test_cli_input_selection() {
+        let source = |args: &[&str]| {
+            let Command::Run { input, .. } = Cli::try_parse_from(args).unwrap().command else { panic!("expected run") };
+            input.source()
+        };
+        assert_eq!(source(&["aoc", "run", "5", "--example"]), InputSource::Example);
+        assert_eq!(source(&["aoc", "run", "5", "--input", "-"]), InputSource::Stdin);
+        assert_eq!(
+            source(&["aoc", "run", "5", "--input", "mine.txt"]),
+            InputSource::File("mine.txt".into())
+        );
+        assert!(Cli::try_parse_from(["aoc", "run", "5", "--example", "--input", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_params() {
+        let args = |argv: &[&str]| {
+            let Command::Run { params, .. } = Cli::try_parse_from(argv).unwrap().command else { panic!("expected run") };
+            params
+        };
+        let all = selected_days(DaySelector::All).unwrap();
+        let config = Config::parse("[day08.example]\nconnections = 10\n").unwrap();
+
+        let resolved = args(&["aoc", "run", "all"]).resolve(&config, &all, &InputSource::Example).unwrap();
+        let day08 = all.iter().position(|day| day.day == 8).unwrap();
+        assert_eq!(resolved[day08].get::<usize>("connections"), 10);
+        let resolved = args(&["aoc", "run", "all"]).resolve(&config, &all, &InputSource::Default).unwrap();
+        assert_eq!(resolved[day08].get::<usize>("connections"), 1000);
+
+        let overridden = args(&["aoc", "run", "all", "--param", "connections=20", "--param", "neighbour_limit = 5"]);
+        let resolved = overridden.resolve(&config, &all, &InputSource::Example).unwrap();
+        assert_eq!(resolved[day08].get::<usize>("connections"), 20);
+        assert_eq!(resolved[all.iter().position(|day| day.day == 4).unwrap()].get::<usize>("neighbour_limit"), 5);
+
+        let day05 = selected_days(DaySelector::One(5)).unwrap();
+        let error = args(&["aoc", "run", "5", "--param", "connections=20"]).resolve(&config, &day05, &InputSource::Default);
+        assert_eq!(error.unwrap_err(), "--param connections: day 5: there is no parameter `connections`; this day has none");
+        let error = args(&["aoc", "run", "all", "--param", "connections=-1"]).resolve(&config, &all, &InputSource::Default);
+        assert_eq!(error.unwrap_err(), "--param connections: day 8: `connections` must be between 0 and 4294967295, got -1");
+        assert!(Cli::try_parse_from(["aoc", "run", "8", "--param", "connections"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "bench", "8", "--param", "connections=ten"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_bench() {
+        let cli = Cli::try_parse_from(["aoc", "bench", "all", "--samples", "5", "--example"]).unwrap();
+        let Command::Bench { day, warmup, samples, input, .. } = cli.command else { panic!("expected bench") };
+        assert_eq!((day, warmup, samples), (DaySelector::All, 3, 5));
+        assert_eq!(input.source(), InputSource::Example);
+        assert!(Cli::try_parse_from(["aoc", "bench", "5", "--samples", "0"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "bench", "5", "--fail-on-regression"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_new() {
+        let cli = Cli::try_parse_from(["aoc", "new", "10", "--template", "grid"]).unwrap();
+        let Command::New { day, template } = cli.command else { panic!("expected new") };
+        assert_eq!((day, template), (10, scaffold::Template::Grid));
+        assert!(Cli::try_parse_from(["aoc", "new", "26"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "new", "10", "--template", "tree"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_watch() {
+        let cli = Cli::try_parse_from(["aoc", "watch", "9", "--example", "--param", "connections=10"]).unwrap();
+        let Command::Watch { day, input, params } = cli.command else { panic!("expected watch") };
+        assert_eq!((day, input.source()), (9, InputSource::Example));
+        assert_eq!(params.params, vec![("connections".to_string(), 10)]);
+        assert!(Cli::try_parse_from(["aoc", "watch", "all"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_generate() {
+        let cli = Cli::try_parse_from(["aoc", "generate", "8", "--size", "5000", "--seed", "1", "--output", "big.txt"]).unwrap();
+        let Command::Generate { day, size, seed, output } = cli.command else { panic!("expected generate") };
+        assert_eq!((day, size, seed, output), (8, 5000, Some(1), Some(PathBuf::from("big.txt"))));
+        let cli = Cli::try_parse_from(["aoc", "generate", "3"]).unwrap();
+        assert!(matches!(cli.command, Command::Generate { size: 1000, seed: None, output: None, .. }));
+        assert!(Cli::try_parse_from(["aoc", "generate", "all"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_fetch() {
+        let cli = Cli::try_parse_from(["aoc", "fetch", "all"]).unwrap();
+        assert!(matches!(cli.command, Command::Fetch { day: DaySelector::All }));
+        assert!(Cli::try_parse_from(["aoc", "fetch", "30"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_inputs() {
+        let cli = Cli::try_parse_from(["aoc", "inputs", "encrypt", "all", "--key-file", "team.key"]).unwrap();
+        let Command::Inputs { action: InputsAction::Encrypt { day, key } } = cli.command else { panic!("expected encrypt") };
+        assert_eq!((day, key.key_file), (DaySelector::All, Some(PathBuf::from("team.key"))));
+        let cli = Cli::try_parse_from(["aoc", "inputs", "decrypt", "5"]).unwrap();
+        assert!(matches!(cli.command, Command::Inputs { action: InputsAction::Decrypt { day: DaySelector::One(5), .. } }));
+        assert!(Cli::try_parse_from(["aoc", "inputs", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_examples() {
+        let cli = Cli::try_parse_from(["aoc", "examples", "5", "--page", "day5.html"]).unwrap();
+        let Command::Examples { day, page } = cli.command else { panic!("expected examples") };
+        assert_eq!((day, page), (5, Some(PathBuf::from("day5.html"))));
+        assert!(Cli::try_parse_from(["aoc", "examples", "all"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_submit() {
+        let cli = Cli::try_parse_from(["aoc", "submit", "3", "2"]).unwrap();
+        assert!(matches!(cli.command, Command::Submit { day: 3, part: 2 }));
+        assert!(Cli::try_parse_from(["aoc", "submit", "3", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_baseline_regression() {
+        let dir = aoc_common::TempDir::new();
+        let args = |extra: &[&str]| {
+            let path = dir.path().join("machine.toml");
+            let mut argv = vec!["aoc", "bench", "5", "--baseline", path.to_str().unwrap()];
+            argv.extend_from_slice(extra);
+            let Command::Bench { baseline, .. } = Cli::try_parse_from(argv).unwrap().command else { panic!("expected bench") };
+            baseline
+        };
+        let benched = |micros, params: &str| {
+            let d = std::time::Duration::from_micros(micros);
+            let setup = baseline::Setup { input_hash: "abc".to_string(), params: params.to_string(), profile: "debug".to_string() };
+            [(5, vec![(aoc_common::Stage::Parse, aoc_common::Stats { mean: d, median: d, p95: d })], setup)]
+        };
+
+        assert!(args(&["--compare"]).apply(&benched(100, "")).is_err());
+        assert_eq!(args(&["--save-baseline"]).apply(&benched(100, "")), Ok(true));
+        assert_eq!(args(&["--compare"]).apply(&benched(200, "")), Ok(true));
+        assert_eq!(args(&["--compare", "--fail-on-regression"]).apply(&benched(200, "")), Ok(false));
+        assert_eq!(args(&["--compare", "--fail-on-regression", "--threshold", "150"]).apply(&benched(200, "")), Ok(true));
+        // Timings with other parameters are not compared, so they cannot regress.
+        assert_eq!(args(&["--compare", "--fail-on-regression"]).apply(&benched(200, "x=1")), Ok(true));
+    }
+
+    #[test]
+    fn test_bench_example() {
+        let config = BenchConfig { warmup: 0, samples: 1 };
+        let params = Params::default();
+        let (day, timings, setup) = bench(days::find(1).unwrap(), &Part::ALL, &InputSource::Example, &params, &config, false)
+            .unwrap()
+            .unwrap();
+        // Day 1 has separate examples for both parts, so it is parsed twice.
+        assert_eq!((day, timings.len()), (1, 4));
+        assert_eq!(setup.input_hash.split(',').count(), 2);
+        let missing = InputSource::File("no_such_input.txt".into());
+        assert_eq!(bench(days::find(1).unwrap(), &Part::ALL, &missing, &params, &config, true).unwrap(), None);
+    }
+}
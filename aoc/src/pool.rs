@@ -0,0 +1,131 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// One worker per available core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+type Hook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+thread_local! {
+    /// Whether this thread is a worker of [`map`], whose panics are captured instead of printed.
+    static WORKER: Cell<bool> = const { Cell::new(false) };
+    /// The message and location of the last panic of this worker.
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// How many calls to [`map`] are running, and the hook to restore when none are.
+static HOOK: Mutex<(usize, Option<Arc<Hook>>)> = Mutex::new((0, None));
+
+/// Keeps panics of workers out of the output for as long as it lives; other
+/// threads still panic through the hook that was installed before.
+struct CaptureGuard;
+
+impl CaptureGuard {
+    fn install() -> CaptureGuard {
+        let mut hook = HOOK.lock().unwrap_or_else(|e| e.into_inner());
+        if hook.0 == 0 {
+            let previous = Arc::new(panic::take_hook());
+            let fallback = Arc::clone(&previous);
+            panic::set_hook(Box::new(move |info| {
+                if WORKER.get() {
+                    let message = panic_message(info.payload());
+                    let captured = match info.location() {
+                        Some(location) => format!("{}, at {}", message, location),
+                        None => message,
+                    };
+                    CAPTURED.set(Some(captured));
+                } else {
+                    fallback(info);
+                }
+            }));
+            hook.1 = Some(previous);
+        }
+        hook.0 += 1;
+        CaptureGuard
+    }
+}
+
+impl Drop for CaptureGuard {
+    fn drop(&mut self) {
+        let mut hook = HOOK.lock().unwrap_or_else(|e| e.into_inner());
+        hook.0 -= 1;
+        if hook.0 == 0
+            && let Some(previous) = hook.1.take()
+        {
+            // Dropping the capturing hook releases its reference to the previous one.
+            drop(panic::take_hook());
+            panic::set_hook(Arc::try_unwrap(previous).unwrap_or_else(|previous| Box::new(move |info| previous(info))));
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<String>() {
+        Some(message) => message.clone(),
+        None => payload.downcast_ref::<&str>().map_or("unknown panic".to_string(), |s| s.to_string()),
+    }
+}
+
+/// Applies `f` to every item on up to `jobs` threads and returns the results in the order of `items`.
+///
+/// A panic in `f` only fails its own item, as an `Err` with the panic message and
+/// where it happened; it is not printed.
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<Result<R, String>> {
+    let _capture = CaptureGuard::install();
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> = Mutex::new(items.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                WORKER.set(true);
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else { break };
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                        .map_err(|payload| CAPTURED.take().unwrap_or_else(|| panic_message(payload.as_ref())));
+                    results.lock().unwrap()[i] = Some(result);
+                }
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is taken by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (1..=20).collect();
+        let squares = map(&items, 4, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| Ok(n * n)).collect::<Vec<_>>());
+        assert!(map(&[] as &[u64], 4, |n| *n).is_empty());
+    }
+
+    #[test]
+    fn test_map_isolates_panics() {
+        let results = map(&[1, 0, 2], 2, |&n| {
+            if n == 0 {
+                panic!("division by {}", n);
+            }
+            10 / n
+        });
+        assert_eq!(results[0], Ok(10));
+        assert_eq!(results[2], Ok(5));
+        let failure = results[1].as_ref().unwrap_err();
+        assert!(failure.starts_with("division by 0, at "), "{}", failure);
+        assert!(failure.contains("pool.rs"), "{}", failure);
+    }
+}
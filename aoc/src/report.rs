@@ -0,0 +1,244 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc_common::{Answer, Answers, LoadedInput, Solved};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// How `aoc run` prints its results.
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    /// Answers for people to read
+    Text,
+    /// One JSON document with every answer, its timings and the hash of its input
+    Json,
+}
+
+/// `bytes` in lowercase hex, as digests are written.
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+/// SHA-256 of the input, in hex, so runs on the same input can be matched up.
+pub fn input_hash(contents: &str) -> String {
+    hex(&Sha256::digest(contents.as_bytes()))
+}
+
+/// One solved part.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartRun {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    /// Time to parse the input the part was solved on; `None` for a cached answer,
+    /// which took no parsing, so it is `null` in JSON rather than a time of 0.
+    pub parse_nanos: Option<u64>,
+    /// Time to solve the part; `None` for a cached answer.
+    pub solve_nanos: Option<u64>,
+    /// File the input was read from, or `-` for stdin.
+    pub input: String,
+    pub input_hash: String,
+    /// Whether the answer came from the answer cache, with no time spent on it.
+    #[serde(default)]
+    pub cached: bool,
+}
+
+impl PartRun {
+    /// The runs of `day` for every part solved on `input`.
+    pub fn from_solved(day: u8, input: &LoadedInput, solved: Solved) -> Vec<PartRun> {
+        let path = input.path.as_ref().map_or("-".to_string(), |path| path.display().to_string());
+        let hash = input_hash(&input.contents);
+        solved
+            .answers
+            .into_iter()
+            .map(|(part, answer, solve_time)| PartRun {
+                day,
+                part: part.number(),
+                answer,
+                parse_nanos: Some(solved.parse_time.as_nanos() as u64),
+                solve_nanos: Some(solve_time.as_nanos() as u64),
+                input: path.clone(),
+                input_hash: hash.clone(),
+                cached: false,
+            })
+            .collect()
+    }
+
+    /// The runs of `day` for answers of `input` taken from the answer cache.
+    pub fn from_cache(day: u8, input: &LoadedInput, answers: Answers) -> Vec<PartRun> {
+        let path = input.path.as_ref().map_or("-".to_string(), |path| path.display().to_string());
+        let hash = input_hash(&input.contents);
+        answers
+            .into_iter()
+            .map(|(part, answer)| PartRun {
+                day,
+                part: part.number(),
+                answer,
+                parse_nanos: None,
+                solve_nanos: None,
+                input: path.clone(),
+                input_hash: hash.clone(),
+                cached: true,
+            })
+            .collect()
+    }
+}
+
+/// A day that could not be solved.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Failure {
+    pub day: u8,
+    pub error: String,
+}
+
+/// A day left out of `aoc run all` because its input is missing.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Skip {
+    pub day: u8,
+    pub reason: String,
+}
+
+/// Everything one `aoc run` produced.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: Vec<PartRun>,
+    pub failures: Vec<Failure>,
+    pub skipped: Vec<Skip>,
+    /// Wall-clock time of the whole run, with the days solved concurrently.
+    pub elapsed_nanos: u64,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a report always serializes")
+    }
+
+    /// Time spent parsing and solving `day`; an input solved for both parts is parsed once,
+    /// and cached answers took none.
+    pub fn day_time(&self, day: u8) -> Duration {
+        let runs: Vec<&PartRun> = self.runs.iter().filter(|run| run.day == day).collect();
+        let mut nanos: u64 = runs.iter().filter_map(|run| run.solve_nanos).sum();
+        for (i, run) in runs.iter().enumerate() {
+            if runs[..i].iter().all(|earlier| earlier.input != run.input || earlier.parse_nanos.is_none()) {
+                nanos += run.parse_nanos.unwrap_or(0);
+            }
+        }
+        Duration::from_nanos(nanos)
+    }
+
+    /// Answers and time of every day in `days`, failures and skips included, and a closing summary.
+    pub fn summary_table(&self, days: &[u8]) -> String {
+        let answer = |day: u8, part: u8| {
+            self.runs
+                .iter()
+                .find(|run| run.day == day && run.part == part)
+                .map_or("-".to_string(), |run| run.answer.to_string())
+        };
+        let mut out = format!("{:<5} {:>20} {:>20} {:>12}\n", "day", "part 1", "part 2", "time");
+        for &day in days {
+            if let Some(skip) = self.skipped.iter().find(|skip| skip.day == day) {
+                out.push_str(&format!("{:<5} skipped: {}\n", day, skip.reason));
+                continue;
+            }
+            match self.failures.iter().find(|failure| failure.day == day) {
+                Some(failure) => {
+                    let reason = failure.error.lines().next().unwrap_or_default();
+                    out.push_str(&format!("{:<5} failed: {}\n", day, reason));
+                }
+                None => {
+                    let mut runs = self.runs.iter().filter(|run| run.day == day).peekable();
+                    let cached = runs.peek().is_some() && runs.all(|run| run.cached);
+                    let time = if cached { "cached".to_string() } else { format!("{:.2?}", self.day_time(day)) };
+                    out.push_str(&format!("{:<5} {:>20} {:>20} {:>12}\n", day, answer(day, 1), answer(day, 2), time));
+                }
+            }
+        }
+        out.push_str(&format!(
+            "{} of {} days solved in {:.2?}",
+            days.len() - self.failures.len() - self.skipped.len(),
+            days.len(),
+            Duration::from_nanos(self.elapsed_nanos)
+        ));
+        if !self.failures.is_empty() {
+            out.push_str(&format!(", {} failed", self.failures.len()));
+        }
+        if !self.skipped.is_empty() {
+            out.push_str(&format!(", {} skipped", self.skipped.len()));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Part;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_ne!(input_hash("1,2\n"), input_hash("1,2"));
+    }
+
+    #[test]
+    fn test_json() {
+        let input = LoadedInput { path: Some(PathBuf::from("day05/input.txt")), contents: String::new(), parts: Part::ALL.to_vec() };
+        let solved = Solved {
+            parse_time: Duration::from_micros(3),
+            answers: vec![(Part::Two, Answer::from(u128::MAX), Duration::from_nanos(250))],
+        };
+        let report = Report {
+            runs: PartRun::from_solved(5, &input, solved),
+            failures: vec![Failure { day: 8, error: "invalid input".to_string() }],
+            skipped: vec![Skip { day: 9, reason: "day09/input.txt does not exist".to_string() }],
+            elapsed_nanos: 5000,
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        let run = &json["runs"][0];
+        assert_eq!((run["day"].as_u64(), run["part"].as_u64()), (Some(5), Some(2)));
+        assert_eq!(run["answer"], "340282366920938463463374607431768211455");
+        assert_eq!((run["parse_nanos"].as_u64(), run["solve_nanos"].as_u64()), (Some(3000), Some(250)));
+
+        let cached = Report { runs: PartRun::from_cache(5, &input, vec![(Part::One, Answer::Int(3))]), ..Default::default() };
+        let cached_json: serde_json::Value = serde_json::from_str(&cached.to_json()).unwrap();
+        let cached_run = &cached_json["runs"][0];
+        assert!(cached_run["parse_nanos"].is_null() && cached_run["solve_nanos"].is_null());
+        assert_eq!(cached_run["cached"], true);
+        assert_eq!(serde_json::from_str::<Report>(&cached.to_json()).unwrap(), cached);
+        assert_eq!(run["input"], "day05/input.txt");
+        assert_eq!(run["input_hash"], input_hash(""));
+        assert_eq!(json["failures"][0]["day"], 8);
+        assert_eq!(json["skipped"][0]["reason"], "day09/input.txt does not exist");
+        assert_eq!(json["elapsed_nanos"], 5000);
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let input = LoadedInput { path: Some(PathBuf::from("input.txt")), contents: String::new(), parts: Part::ALL.to_vec() };
+        let solved = Solved {
+            parse_time: Duration::from_micros(10),
+            answers: vec![(Part::One, Answer::Int(3), Duration::from_micros(1)), (Part::Two, Answer::Int(6), Duration::from_micros(2))],
+        };
+        let mut report = Report {
+            runs: PartRun::from_solved(1, &input, solved),
+            failures: vec![Failure { day: 8, error: "panicked: index out of bounds\nmore".to_string() }],
+            skipped: vec![Skip { day: 9, reason: "day09/input.txt does not exist".to_string() }],
+            elapsed_nanos: 2_000_000,
+        };
+        report.runs.extend(PartRun::from_cache(2, &input, vec![(Part::One, Answer::Int(7))]));
+
+        assert_eq!(report.day_time(1), Duration::from_micros(13));
+        let table = report.summary_table(&[1, 2, 8, 9]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[1].split_whitespace().collect::<Vec<_>>(), vec!["1", "3", "6", "13.00µs"]);
+        assert_eq!(lines[2].split_whitespace().collect::<Vec<_>>(), vec!["2", "7", "-", "cached"]);
+        assert_eq!(lines[3], "8     failed: panicked: index out of bounds");
+        assert_eq!(lines[4], "9     skipped: day09/input.txt does not exist");
+        assert_eq!(lines[5], "2 of 4 days solved in 2.00ms, 1 failed, 1 skipped");
+    }
+}
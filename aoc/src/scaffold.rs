@@ -0,0 +1,209 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../../dayXX/Cargo.toml");
+const MAIN_RS: &str = include_str!("../../dayXX/src/main.rs");
+const BUILD_RS: &str = include_str!("../../dayXX/build.rs");
+const ANSWERS_TOML: &str = include_str!("../../dayXX/answers.toml");
+
+/// Shape of the input the new day's parser starts out with.
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Template {
+    /// One entry per non-blank line
+    Lines,
+    /// A rectangular grid of characters
+    Grid,
+    /// Groups of lines separated by blank lines
+    Sections,
+    /// Comma-separated numbers on every line
+    Numbers,
+}
+
+impl Template {
+    fn lib_rs(self) -> &'static str {
+        match self {
+            Template::Lines => include_str!("../../dayXX/src/lib.rs"),
+            Template::Grid => include_str!("../../dayXX/templates/grid.rs"),
+            Template::Sections => include_str!("../../dayXX/templates/sections.rs"),
+            Template::Numbers => include_str!("../../dayXX/templates/numbers.rs"),
+        }
+    }
+}
+
+fn crate_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Fills in the template placeholders for `day`.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+        .replace("DayXX", &format!("Day{:02}", day))
+        .replace("dayXX", &crate_name(day))
+        .replace("dayxx", &crate_name(day))
+        .replace("Day XX", &format!("Day {}", day))
+}
+
+/// Adds `member` to the `members` list of the workspace manifest, keeping the days in order.
+fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start = manifest.find("members = [").ok_or("no `members = [...]` in Cargo.toml")? + "members = [".len();
+    let end = start + manifest[start..].find(']').ok_or("unterminated `members` list in Cargo.toml")?;
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&member) {
+        return Err(format!("{} is already a workspace member", member));
+    }
+    let at = members
+        .iter()
+        .position(|m| m.starts_with("day") && (*m == "dayXX" || *m > member))
+        .unwrap_or(members.len());
+    members.insert(at, member);
+    let list: Vec<String> = members.iter().map(|m| format!("\"{}\"", m)).collect();
+    Ok(format!("{}{}{}", &manifest[..start], list.join(", "), &manifest[end..]))
+}
+
+/// Inserts `line` among the lines of `text` that start with `prefix`, in sorted order.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|l| l.starts_with(prefix))
+        .ok_or_else(|| format!("no line starting with `{}`", prefix.trim()))?;
+    if lines.contains(&line) {
+        return Err(format!("`{}` is already there", line.trim()));
+    }
+    let at = lines
+        .iter()
+        .position(|l| l.starts_with(prefix) && *l > line)
+        .unwrap_or(last + 1);
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String, String>) -> io::Result<()> {
+    let text = fs::read_to_string(path)?;
+    let edited = f(&text).map_err(|e| io::Error::other(format!("{}: {}", path.display(), e)))?;
+    fs::write(path, edited)
+}
+
+/// Creates `dayNN` in the workspace at `root` and registers it with the workspace and the runner.
+///
+/// Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u8, template: Template) -> io::Result<Vec<PathBuf>> {
+    let name = crate_name(day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", dir.display())));
+    }
+
+    // Check every edit before touching anything, so a failure leaves no half-made day behind.
+    let manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let days_rs = root.join("aoc").join("src").join("days.rs");
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+    let registration = format!("    Day::new::<{}::Day{:02}>(),", name, day);
+    add_member(&fs::read_to_string(&manifest)?, &name).map_err(io::Error::other)?;
+    insert_sorted(&fs::read_to_string(&runner_manifest)?, "day", &dependency).map_err(io::Error::other)?;
+    insert_sorted(&fs::read_to_string(&days_rs)?, "    Day::new::<day", &registration).map_err(io::Error::other)?;
+
+    let files = [
+        ("Cargo.toml", render(CARGO_TOML, day)),
+        ("src/lib.rs", render(template.lib_rs(), day)),
+        ("src/main.rs", render(MAIN_RS, day)),
+        ("build.rs", BUILD_RS.to_string()),
+        ("answers.toml", ANSWERS_TOML.to_string()),
+        ("test-part1.txt", String::new()),
+        ("input.txt", String::new()),
+    ];
+    let mut changed = Vec::new();
+    fs::create_dir_all(dir.join("src"))?;
+    for (file, contents) in files {
+        let path = dir.join(file);
+        fs::write(&path, contents)?;
+        changed.push(path);
+    }
+
+    edit(&manifest, |text| add_member(text, &name))?;
+    edit(&runner_manifest, |text| insert_sorted(text, "day", &dependency))?;
+    edit(&days_rs, |text| insert_sorted(text, "    Day::new::<day", &registration))?;
+    changed.extend([manifest, runner_manifest, days_rs]);
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TempDir;
+
+    const WORKSPACE: &str = "[workspace]\nresolver = \"3\"\nmembers = [\"aoc\", \"aoc-common\", \"day01\", \"day03\", \"dayXX\"]\n";
+    const RUNNER: &str = "[dependencies]\naoc-common = { path = \"../aoc-common\" }\nclap = \"4\"\nday01 = { path = \"../day01\" }\nday03 = { path = \"../day03\" }\ntoml = \"1\"\n";
+    const DAYS: &str = "pub const DAYS: &[Day] = &[\n    Day::new::<day01::Day01>(),\n    Day::new::<day03::Day03>(),\n];\n";
+
+    fn workspace() -> TempDir {
+        let dir = TempDir::new();
+        dir.file("Cargo.toml", WORKSPACE);
+        fs::create_dir_all(dir.path().join("aoc/src")).unwrap();
+        dir.file("aoc/Cargo.toml", RUNNER);
+        dir.file("aoc/src/days.rs", DAYS);
+        dir
+    }
+
+    #[test]
+    fn test_render() {
+        let lib = render(Template::Grid.lib_rs(), 7);
+        assert!(lib.starts_with("//! Day 7\n"));
+        assert!(lib.contains("pub struct Day07;") && lib.contains("const DAY: u8 = 7;"));
+        assert!(!lib.contains("XX") && !lib.contains("xx"));
+
+        let manifest = render(CARGO_TOML, 12);
+        assert!(manifest.contains("name = \"day12\"") && !manifest.contains("XX") && !manifest.contains("xx"));
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest = add_member(WORKSPACE, "day02").unwrap();
+        assert!(manifest.contains("members = [\"aoc\", \"aoc-common\", \"day01\", \"day02\", \"day03\", \"dayXX\"]\n"));
+        let manifest = add_member(WORKSPACE, "day10").unwrap();
+        assert!(manifest.contains("\"day03\", \"day10\", \"dayXX\""));
+        assert!(add_member(WORKSPACE, "day03").is_err());
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let days = insert_sorted(DAYS, "    Day::new::<day", "    Day::new::<day02::Day02>(),").unwrap();
+        assert!(days.contains("Day01>(),\n    Day::new::<day02::Day02>(),\n    Day::new::<day03"));
+        let runner = insert_sorted(RUNNER, "day", "day10 = { path = \"../day10\" }").unwrap();
+        assert!(runner.contains("day03 = { path = \"../day03\" }\nday10 = { path = \"../day10\" }\ntoml"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = workspace();
+        new_day(root.path(), 2, Template::Sections).unwrap();
+
+        let dir = root.path().join("day02");
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("sections(input)") && lib.contains("answer_tests!(Day02)"));
+        assert_eq!(fs::read_to_string(dir.join("test-part1.txt")).unwrap(), "");
+        assert!(dir.join("input.txt").exists() && dir.join("answers.toml").exists());
+        assert!(fs::read_to_string(dir.join("build.rs")).unwrap().contains("aoc_missing_input"));
+        assert!(fs::read_to_string(root.path().join("Cargo.toml")).unwrap().contains("\"day02\""));
+        assert!(fs::read_to_string(root.path().join("aoc/src/days.rs")).unwrap().contains("day02::Day02"));
+
+        let err = new_day(root.path(), 2, Template::Lines).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    }
+
+    #[test]
+    fn test_new_day_leaves_nothing_behind_on_error() {
+        let root = workspace();
+        root.file("aoc/src/days.rs", "pub const DAYS: &[Day] = &[];\n");
+        assert!(new_day(root.path(), 2, Template::Lines).is_err());
+        assert!(!root.path().join("day02").exists());
+        assert_eq!(fs::read_to_string(root.path().join("Cargo.toml")).unwrap(), WORKSPACE);
+    }
+}
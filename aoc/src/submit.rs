@@ -0,0 +1,350 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::html;
+use crate::web::{Client, WebError, YEAR};
+
+/// How long the site makes us wait after a wrong answer when the page does not say.
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// What the site thought of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// The answer page, boiled down.
+#[derive(Debug, PartialEq)]
+pub enum Response {
+    /// `wait` is how long until the next answer is accepted.
+    Answered { verdict: Verdict, wait: Option<Duration> },
+    RateLimited(Duration),
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+/// Text of the page's `<article>`s, without tags.
+fn article_text(page: &str) -> String {
+    let articles = html::elements(page, "article");
+    let text = if articles.is_empty() { html::text(page) } else { html::text(&articles.join(" ")) };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses waits like `1m 3s`, `47s` or `5 minutes` out of `text`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut seconds = 0;
+    let mut found = false;
+    for (i, word) in words.iter().enumerate() {
+        let word = word.trim_end_matches([',', '.', ';']);
+        let unit = word.strip_suffix('m').map(|n| (n, 60)).or_else(|| word.strip_suffix('s').map(|n| (n, 1)));
+        if let Some((n, unit)) = unit
+            && let Ok(n) = n.parse::<u64>()
+        {
+            seconds += n * unit;
+            found = true;
+        } else if let Some(next) = words.get(i + 1) {
+            let n = match word {
+                "one" | "a" => Some(1),
+                _ => word.parse::<u64>().ok(),
+            };
+            if let Some(n) = n {
+                if next.starts_with("minute") {
+                    seconds += n * 60;
+                    found = true;
+                } else if next.starts_with("second") {
+                    seconds += n;
+                    found = true;
+                }
+            }
+        }
+    }
+    found.then(|| Duration::from_secs(seconds))
+}
+
+pub fn parse_response(page: &str) -> Response {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        Response::Answered { verdict: Verdict::Right, wait: None }
+    } else if text.contains("That's not the right answer") {
+        let verdict = if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        let wait = text.find("wait").and_then(|at| parse_wait(&text[at..]));
+        Response::Answered { verdict, wait: Some(wait.unwrap_or(DEFAULT_WAIT)) }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text.find("you have").and_then(|at| parse_wait(&text[at..]));
+        Response::RateLimited(wait.unwrap_or(DEFAULT_WAIT))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unknown(text)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+/// Answers submitted for one day, and when the site accepts the next one.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub wait_until: u64,
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+/// Why an answer is not sent at all.
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    AlreadySolved(Answer),
+    AlreadySubmitted(Verdict),
+    /// An answer at least this large was already too high.
+    AboveTooHigh(Answer),
+    /// An answer at least this small was already too low.
+    BelowTooLow(Answer),
+    Wait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved with {}", answer),
+            Refusal::AlreadySubmitted(verdict) => write!(f, "this answer was already submitted and was {}", verdict),
+            Refusal::AboveTooHigh(bound) => write!(f, "{} was already too high", bound),
+            Refusal::BelowTooLow(bound) => write!(f, "{} was already too low", bound),
+            Refusal::Wait(wait) => write!(f, "the site accepts the next answer in {}s", wait.as_secs()),
+        }
+    }
+}
+
+/// `.aoc-cache/submissions/2025/dayNN.toml`.
+pub fn history_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join("submissions").join(YEAR.to_string()).join(format!("day{:02}.toml", day))
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+impl History {
+    pub fn load(path: &Path) -> io::Result<History> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let s = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+
+    /// Refuses answers the site is sure to reject, or that it would not accept yet.
+    pub fn check(&self, part: Part, answer: &Answer, now: u64) -> Result<(), Refusal> {
+        let earlier = self.submissions.iter().filter(|s| s.part == part.number());
+        let number = answer.as_int();
+        for submission in earlier {
+            if submission.verdict == Verdict::Right {
+                return Err(Refusal::AlreadySolved(submission.answer.clone()));
+            }
+            if submission.answer == *answer {
+                return Err(Refusal::AlreadySubmitted(submission.verdict));
+            }
+            match (number, submission.answer.as_int(), submission.verdict) {
+                (Some(n), Some(bound), Verdict::TooHigh) if n >= bound => {
+                    return Err(Refusal::AboveTooHigh(submission.answer.clone()));
+                }
+                (Some(n), Some(bound), Verdict::TooLow) if n <= bound => {
+                    return Err(Refusal::BelowTooLow(submission.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+        if now < self.wait_until {
+            return Err(Refusal::Wait(Duration::from_secs(self.wait_until - now)));
+        }
+        Ok(())
+    }
+
+    /// Remembers what the site said about `answer`.
+    pub fn record(&mut self, part: Part, answer: &Answer, response: &Response, now: u64) {
+        match response {
+            Response::Answered { verdict, wait } => {
+                self.submissions.push(Submission {
+                    part: part.number(),
+                    answer: answer.clone(),
+                    verdict: *verdict,
+                    at: now,
+                });
+                if let Some(wait) = wait {
+                    self.wait_until = now + wait.as_secs();
+                }
+            }
+            Response::RateLimited(wait) => self.wait_until = now + wait.as_secs(),
+            Response::WrongLevel | Response::Unknown(_) => {}
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Web(WebError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted: {}", refusal),
+            SubmitError::Web(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Sends `answer` unless the history rules it out, and records the response.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    now: u64,
+) -> Result<Response, SubmitError> {
+    history.check(part, answer, now).map_err(SubmitError::Refused)?;
+    let page = client.submit(day, part, answer).map_err(SubmitError::Web)?;
+    let response = parse_response(&page);
+    history.record(part, answer, &response, now);
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{MockServer, TempDir};
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", text)
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let right = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(parse_response(&right), Response::Answered { verdict: Verdict::Right, wait: None });
+
+        let high = page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.");
+        assert_eq!(
+            parse_response(&high),
+            Response::Answered { verdict: Verdict::TooHigh, wait: Some(Duration::from_secs(60)) }
+        );
+
+        let low = page("That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.");
+        assert_eq!(
+            parse_response(&low),
+            Response::Answered { verdict: Verdict::TooLow, wait: Some(Duration::from_secs(300)) }
+        );
+
+        let limited = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait.");
+        assert_eq!(parse_response(&limited), Response::RateLimited(Duration::from_secs(63)));
+
+        let level = page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_response(&level), Response::WrongLevel);
+        assert_eq!(parse_response("<p>Hmm</p>"), Response::Unknown("Hmm".to_string()));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+        let high = Response::Answered { verdict: Verdict::TooHigh, wait: Some(Duration::from_secs(60)) };
+        history.record(Part::One, &Answer::Int(500), &high, 1000);
+
+        assert_eq!(history.check(Part::One, &Answer::Int(400), 1030), Err(Refusal::Wait(Duration::from_secs(30))));
+        assert_eq!(history.check(Part::One, &Answer::Int(500), 2000), Err(Refusal::AlreadySubmitted(Verdict::TooHigh)));
+        assert_eq!(history.check(Part::One, &Answer::Int(600), 2000), Err(Refusal::AboveTooHigh(Answer::Int(500))));
+        assert_eq!(history.check(Part::One, &Answer::Int(400), 2000), Ok(()));
+        assert_eq!(history.check(Part::Two, &Answer::Int(600), 2000), Ok(()));
+
+        history.record(Part::One, &Answer::Int(400), &Response::Answered { verdict: Verdict::Right, wait: None }, 2000);
+        assert_eq!(history.check(Part::One, &Answer::Int(399), 3000), Err(Refusal::AlreadySolved(Answer::Int(400))));
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let dir = TempDir::new();
+        let path = history_path(dir.path(), 3);
+        assert_eq!(History::load(&path).unwrap(), History::default());
+
+        let mut history = History::default();
+        let low = Response::Answered { verdict: Verdict::TooLow, wait: Some(Duration::from_secs(60)) };
+        history.record(Part::Two, &Answer::Int(17), &low, 1000);
+        history.save(&path).unwrap();
+
+        assert_eq!(History::load(&path).unwrap(), history);
+        assert!(fs::read_to_string(&path).unwrap().contains("verdict = \"too-low\""));
+    }
+
+    #[test]
+    fn test_submit_against_stub_server() {
+        let server = MockServer::start(vec![
+            (200, &page("That's not the right answer.  If you're stuck, ... Please wait one minute before trying again.")),
+            (200, &page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait.")),
+            (200, &page("That's the right answer!")),
+        ]);
+        let client = Client::new(server.url(), "abc123");
+        let mut history = History::default();
+
+        let response = submit(&client, &mut history, 2, Part::One, &Answer::Int(11), 1000).unwrap();
+        assert_eq!(response, Response::Answered { verdict: Verdict::Wrong, wait: Some(Duration::from_secs(60)) });
+
+        // Neither the same wrong answer nor anything before the wait is over reaches the server.
+        let err = submit(&client, &mut history, 2, Part::One, &Answer::Int(11), 2000).unwrap_err();
+        assert_eq!(err, SubmitError::Refused(Refusal::AlreadySubmitted(Verdict::Wrong)));
+        let err = submit(&client, &mut history, 2, Part::One, &Answer::Int(12), 1010).unwrap_err();
+        assert_eq!(err, SubmitError::Refused(Refusal::Wait(Duration::from_secs(50))));
+
+        let response = submit(&client, &mut history, 2, Part::One, &Answer::Int(12), 1060).unwrap();
+        assert_eq!(response, Response::RateLimited(Duration::from_secs(45)));
+        assert_eq!(history.wait_until, 1105);
+
+        let response = submit(&client, &mut history, 2, Part::One, &Answer::Int(12), 1105).unwrap();
+        assert_eq!(response, Response::Answered { verdict: Verdict::Right, wait: None });
+        assert_eq!(server.requests().len(), 3);
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::{Answer, PartAnswers};
+
+use crate::files::{self, snapshot, Snapshot};
+use crate::report::Report;
+
+/// How often the watched files are looked at.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Target directory of the rebuilt runner, so it never replaces the running one.
+const TARGET_DIR: &str = "target/watch";
+
+/// Everything the answers of `day` depend on: the sources of the day and of
+/// aoc-common, the day's inputs and known answers, and `aoc.toml`.
+pub fn watched_paths(workspace: &Path, day: u8) -> Vec<PathBuf> {
+    let day_dir = workspace.join(format!("day{:02}", day));
+    let mut paths = files::source_paths(workspace, day);
+    for file in ["input.txt", "input.txt.enc", "test-part1.txt", "test-part2.txt", "answers.toml"] {
+        paths.push(day_dir.join(file));
+    }
+    paths.push(workspace.join(aoc_common::CONFIG_FILE));
+    paths
+}
+
+/// Files created, changed or removed between `before` and `after`.
+pub fn changed(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, stamp)| before.get(*path) != Some(stamp))
+        .map(|(path, _)| path.clone())
+        .collect();
+    paths.extend(before.keys().filter(|path| !after.contains_key(*path)).cloned());
+    paths.sort();
+    paths
+}
+
+/// Waits until something under `paths` changes and stays unchanged for one
+/// poll, so a save that writes in several steps triggers one run.
+pub fn wait_for_change(paths: &[PathBuf], before: &Snapshot) -> (Snapshot, Vec<PathBuf>) {
+    let mut current = snapshot(paths);
+    while changed(before, &current).is_empty() {
+        thread::sleep(POLL_INTERVAL);
+        current = snapshot(paths);
+    }
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let settled = snapshot(paths);
+        if settled == current {
+            let files = changed(before, &current);
+            return (current, files);
+        }
+        current = settled;
+    }
+}
+
+/// Rebuilds the runner and solves `day` with `args`, as `aoc run <day> --format json <args>`.
+///
+/// Build errors and failures of the day are left on stderr; `Err` says which step failed.
+pub fn rebuild_and_run(workspace: &Path, day: u8, args: &[String]) -> Result<Report, String> {
+    let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .current_dir(workspace)
+        .args(["run", "--quiet", "--target-dir", TARGET_DIR, "--bin", "aoc", "--", "run"])
+        .arg(day.to_string())
+        .args(["--format", "json", "--force"])
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("could not start cargo: {}", e))?;
+    if output.stdout.is_empty() {
+        return Err("the build failed".to_string());
+    }
+    serde_json::from_slice(&output.stdout).map_err(|e| format!("unexpected output of aoc run: {}", e))
+}
+
+/// The answers and timings of one run, each checked against `known`.
+pub fn summary(report: &Report, known: Option<&PartAnswers>) -> String {
+    let mut out = String::new();
+    for run in &report.runs {
+        let expected: Option<&Answer> = known.and_then(|known| match run.part {
+            1 => known.part1.as_ref(),
+            _ => known.part2.as_ref(),
+        });
+        let check = match expected {
+            Some(expected) if *expected == run.answer => "right".to_string(),
+            Some(expected) => format!("WRONG, expected {}", expected),
+            None => "no known answer".to_string(),
+        };
+        let _ = writeln!(
+            out,
+            "Day {} part {}: {:>20} {:>12}  {}",
+            run.day,
+            run.part,
+            run.answer.to_string(),
+            run.solve_nanos.map_or("cached".to_string(), |nanos| format!("{:.2?}", Duration::from_nanos(nanos))),
+            check
+        );
+    }
+    for failure in &report.failures {
+        let _ = writeln!(out, "Day {} failed", failure.day);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use crate::report::{Failure, PartRun};
+
+    #[test]
+    fn test_changed() {
+        let dir = aoc_common::TempDir::new();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        let lib = dir.file("src/lib.rs", "fn main() {}");
+        let input = dir.path().join("input.txt");
+        let paths = vec![dir.path().join("src"), input.clone()];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.keys().collect::<Vec<_>>(), vec![&lib]);
+        assert!(changed(&before, &snapshot(&paths)).is_empty());
+
+        dir.file("input.txt", "1\n");
+        dir.file("src/lib.rs", "fn main() { }");
+        let after = snapshot(&paths);
+        assert_eq!(changed(&before, &after), vec![input.clone(), lib]);
+        fs::remove_file(&input).unwrap();
+        assert_eq!(changed(&after, &snapshot(&paths)), vec![input]);
+    }
+
+    #[test]
+    fn test_watched_paths() {
+        let paths = watched_paths(Path::new("/aoc"), 9);
+        assert!(paths.contains(&PathBuf::from("/aoc/day09/src")));
+        assert!(paths.contains(&PathBuf::from("/aoc/day09/input.txt")));
+        assert!(paths.contains(&PathBuf::from("/aoc/aoc-common/src")));
+    }
+
+    #[test]
+    fn test_summary() {
+        let run = |part, answer: i64| PartRun {
+            day: 9,
+            part,
+            answer: Answer::from(answer),
+            parse_nanos: Some(0),
+            solve_nanos: Some(1500),
+            input: "day09/test-part1.txt".to_string(),
+            input_hash: String::new(),
+            cached: false,
+        };
+        let report = Report { runs: vec![run(1, 50), run(2, 0)], ..Default::default() };
+        let known = PartAnswers { part1: Some(Answer::Int(50)), part2: Some(Answer::Int(24)) };
+
+        let lines: Vec<String> = summary(&report, Some(&known)).lines().map(|line| line.split_whitespace().collect::<Vec<_>>().join(" ")).collect();
+        assert_eq!(lines, vec!["Day 9 part 1: 50 1.50µs right", "Day 9 part 2: 0 1.50µs WRONG, expected 24"]);
+        assert!(summary(&report, None).contains("no known answer"));
+        let failed = Report { failures: vec![Failure { day: 9, error: "invalid input".to_string() }], ..Default::default() };
+        assert_eq!(summary(&failed, None), "Day 9 failed\n");
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use aoc_common::{Answer, Part};
+
+pub const YEAR: u16 = 2025;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Overrides the site, e.g. to point the runner at a local test server.
+const BASE_URL_VAR: &str = "AOC_BASE_URL";
+const SESSION_VAR: &str = "AOC_SESSION";
+/// File in the workspace holding the session token; it is gitignored.
+pub const SESSION_FILE: &str = ".aoc-session";
+const USER_AGENT: &str = concat!("aoc2025-runner/", env!("CARGO_PKG_VERSION"));
+
+/// Failure to talk to the Advent of Code website.
+#[derive(Debug, PartialEq)]
+pub enum WebError {
+    NoSession,
+    SessionExpired,
+    NotUnlocked(u8),
+    Status(u16, String),
+    Transport(String),
+}
+
+impl fmt::Display for WebError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebError::NoSession => write!(
+                f,
+                "no session token: set {} or put the `session` cookie of adventofcode.com in {}",
+                SESSION_VAR, SESSION_FILE
+            ),
+            WebError::SessionExpired => write!(f, "the session token was rejected; log in again and update it"),
+            WebError::NotUnlocked(day) => write!(f, "day {} is not unlocked yet", day),
+            WebError::Status(status, body) => write!(f, "the server answered {}: {}", status, body.trim()),
+            WebError::Transport(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for WebError {}
+
+/// The session token from `AOC_SESSION`, else from `.aoc-session` in the workspace.
+pub fn session_token(workspace: &Path) -> Result<String, WebError> {
+    std::env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| fs::read_to_string(workspace.join(SESSION_FILE)).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .ok_or(WebError::NoSession)
+}
+
+/// Client for the puzzle pages of one year, authenticated by a session token.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Client for adventofcode.com (or `AOC_BASE_URL`) with the session of `workspace`.
+    pub fn from_env(workspace: &Path) -> Result<Client, WebError> {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session_token(workspace)?))
+    }
+
+    fn url(&self, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, page)
+    }
+
+    /// Body of a successful response, or the status and body of a failed one.
+    fn send(&self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String, (u16, String)> {
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => {
+                let mut body = String::new();
+                response
+                    .into_reader()
+                    .read_to_string(&mut body)
+                    .map_err(|e| (0, e.to_string()))?;
+                Ok(body)
+            }
+            Err(ureq::Error::Status(status, response)) => Err((status, response.into_string().unwrap_or_default())),
+            Err(e) => Err((0, e.to_string())),
+        }
+    }
+
+    fn error(day: u8, status: u16, body: String) -> WebError {
+        match status {
+            0 => WebError::Transport(body),
+            _ if body.contains("log in") => WebError::SessionExpired,
+            400 | 401 | 403 => WebError::SessionExpired,
+            404 => WebError::NotUnlocked(day),
+            _ => WebError::Status(status, body),
+        }
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String, WebError> {
+        self.send(self.agent.get(&self.url(day, "/input")), None)
+            .map_err(|(status, body)| Client::error(day, status, body))
+    }
+
+    /// Downloads the puzzle page of `day`, with part 2 once part 1 is solved.
+    pub fn page(&self, day: u8) -> Result<String, WebError> {
+        self.send(self.agent.get(&self.url(day, "")), None)
+            .map_err(|(status, body)| Client::error(day, status, body))
+    }
+
+    /// Posts `answer` for `part` of `day` and returns the page describing the verdict.
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<String, WebError> {
+        let (level, answer) = (part.number().to_string(), answer.to_string());
+        let form = [("level", level.as_str()), ("answer", answer.as_str())];
+        self.send(self.agent.post(&self.url(day, "/answer")), Some(&form))
+            .map_err(|(status, body)| Client::error(day, status, body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{MockServer, TempDir};
+
+    #[test]
+    fn test_input() {
+        let server = MockServer::start(vec![(200, "3-5\n10-14\n")]);
+        let client = Client::new(server.url(), "abc123");
+
+        assert_eq!(client.input(5), Ok("3-5\n10-14\n".to_string()));
+        let request = &server.requests()[0];
+        assert!(request.starts_with("GET /2025/day/5/input HTTP/1.1\r\n"));
+        assert!(request.contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_page() {
+        let server = MockServer::start(vec![(200, "<article>Day 5</article>")]);
+        let client = Client::new(server.url(), "abc123");
+
+        assert_eq!(client.page(5), Ok("<article>Day 5</article>".to_string()));
+        assert!(server.requests()[0].starts_with("GET /2025/day/5 HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let server = MockServer::start(vec![
+            (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."),
+            (404, "Please don't repeatedly request this endpoint before it unlocks!"),
+            (500, "oops"),
+        ]);
+        let client = Client::new(server.url(), "expired");
+
+        assert_eq!(client.input(5), Err(WebError::SessionExpired));
+        assert_eq!(client.input(25), Err(WebError::NotUnlocked(25)));
+        assert_eq!(client.input(5), Err(WebError::Status(500, "oops".to_string())));
+    }
+
+    #[test]
+    fn test_submit_posts_form() {
+        let server = MockServer::start(vec![(200, "<article><p>That's the right answer!</p></article>"), (404, "")]);
+        let client = Client::new(server.url(), "abc123");
+
+        assert!(client.submit(3, Part::Two, &Answer::Int(17095)).unwrap().contains("right answer"));
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /2025/day/3/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=17095"));
+        assert_eq!(client.submit(25, Part::One, &Answer::Int(1)), Err(WebError::NotUnlocked(25)));
+    }
+
+    #[test]
+    fn test_session_token_from_file() {
+        let dir = TempDir::new();
+        if std::env::var(SESSION_VAR).is_ok() {
+            return;
+        }
+        assert_eq!(session_token(dir.path()), Err(WebError::NoSession));
+        dir.file(SESSION_FILE, "  abc123\n");
+        assert_eq!(session_token(dir.path()), Ok("abc123".to_string()));
+    }
+}
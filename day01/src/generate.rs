@@ -0,0 +1,25 @@
+use aoc_common::Rng;
+
+/// `size` rotations, each to the left or right by 1 to 999 clicks.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+        input.push_str(&format!("{}{}\n", direction, rng.range(1..=999)));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated::<Day01>(&[0, 1, 100, 5000], &Params::defaults(Day01::PARAMS));
+        assert_eq!(generate(4, &mut Rng::new(1)).lines().count(), 4);
+    }
+}
@@ -0,0 +1,123 @@
+//! Day 1: Secret Entrance
+
+use aoc_common::{non_blank_lines, parse_int, Answer, Params, ParseError, Rng, Solution};
+use aoc_common::tracing::trace;
+
+pub mod generate;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(codes: &Vec<i64>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(codes).into())
+    }
+
+    fn part_2(codes: &Vec<i64>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(codes).into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Parses the rotations; turns to the left are negative.
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    non_blank_lines(input)
+        .into_iter()
+        .map(|(i, s)| {
+            let mut chars = s.chars();
+            let direction = chars.next().unwrap();
+            // Unsigned, so a rotation cannot turn the other way with a sign of its own.
+            let value = parse_int::<u32>(i, s, chars.as_str())? as i64;
+            match direction {
+                'L' => Ok(-value),
+                'R' => Ok(value),
+                _ => Err(ParseError::at(i, s, &s[..direction.len_utf8()], "expected L or R")),
+            }
+    }).collect()
+}
+
+/// Counts the rotations that leave the dial pointing at 0.
+pub fn part_1(codes: &[i64]) -> i64 {
+    let mut ans = 0;
+    let mut pointer: i64 = 50;
+    for &code in codes {
+        pointer = ( pointer + code ) % 100;
+        if pointer == 0 {
+            ans += 1;
+        }
+    }
+    ans
+}
+
+/// Counts every click that moves the dial onto 0.
+pub fn part_2(codes: &[i64]) -> i64 {
+    let mut ans = 0;
+    let mut pointer: i64 = 50;
+    for &code in codes {
+        let mut full_cycles = 0;
+        full_cycles += ( ( pointer + code ) / 100 ).abs();
+        if code > 0 {
+            pointer = ( pointer + code ) % 100;
+        } else if code < 0 {
+            if pointer + code <= 0 && pointer != 0 {
+                full_cycles += 1;
+            }
+            pointer = ( pointer + ( 100 + code % 100 ) ) % 100;
+        }
+        ans += full_cycles;
+        trace!(code, pointer, full_cycles, ans, "turned");
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_example_file() {
+        let result = parse("L50\nR20\nL10").unwrap();
+        
+        assert_eq!(result.len(), 3);
+        assert_eq!(result, vec![-50, 20, -10]);
+    }
+
+    #[test]
+    fn test_large_rotations() {
+        let codes = parse("L4294967295\nR4294967295").unwrap();
+        assert_eq!(codes, vec![-4294967295, 4294967295]);
+        assert_eq!(part_1(&codes), 0);
+        assert_eq!(part_2(&codes), 85899346);
+    }
+
+    #[test]
+    fn test_parse_empty_file() {
+        let result = parse("").unwrap();
+        
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("L50\nX20").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X"));
+
+        let err = parse("L50\nR2o").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "2o"));
+
+        let err = parse("L50\nL-2147483648").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "-2147483648"));
+    }
+
+    aoc_common::answer_tests!(Day01);
+}
@@ -0,0 +1,22 @@
+//! Tells the answer tests whether the puzzle input can be read: `input.txt`, or
+//! `input.txt.enc` with a key. Without it `input_answers` is built as ignored.
+
+use std::env;
+use std::path::Path;
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(aoc_missing_input)");
+    for file in ["input.txt", "input.txt.enc", "../.aoc-inputs-key"] {
+        println!("cargo::rerun-if-changed={}", file);
+    }
+    println!("cargo::rerun-if-env-changed=AOC_INPUTS_PASSPHRASE");
+    println!("cargo::rerun-if-env-changed=AOC_INPUTS_KEY_FILE");
+
+    let key = env::var_os("AOC_INPUTS_PASSPHRASE").is_some_and(|p| !p.is_empty())
+        || env::var_os("AOC_INPUTS_KEY_FILE").is_some()
+        || Path::new("../.aoc-inputs-key").exists();
+    let readable = Path::new("input.txt").exists() || (Path::new("input.txt.enc").exists() && key);
+    if !readable {
+        println!("cargo::rustc-cfg=aoc_missing_input");
+    }
+}
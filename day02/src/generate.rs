@@ -0,0 +1,27 @@
+use aoc_common::Rng;
+
+/// `size` ranges of IDs with up to ten digits, each spanning at most 10 001 IDs.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+            format!("{}-{}", start, start + rng.range(0..=10_000))
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated::<Day02>(&[0, 1, 10], &Params::defaults(Day02::PARAMS));
+        assert_eq!(crate::parse(&generate(7, &mut Rng::new(1))).unwrap().len(), 7);
+    }
+}
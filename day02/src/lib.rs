@@ -0,0 +1,165 @@
+//! Day 2: Gift Shop
+
+use aoc_common::{non_blank_lines, parse_int, Answer, Params, ParseError, Rng, Solution};
+
+pub mod generate;
+
+/// Inclusive range of product IDs.
+#[derive(PartialEq, Debug)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Interval>;
+
+    fn parse(input: &str) -> Result<Vec<Interval>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(intervals: &Vec<Interval>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(intervals).into())
+    }
+
+    fn part_2(intervals: &Vec<Interval>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(intervals).into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Parses the comma separated ID ranges.
+pub fn parse(input: &str) -> Result<Vec<Interval>, ParseError> {
+    let mut res = Vec::new();
+    let lines = non_blank_lines(input);
+    let Some(&(i, line)) = lines.first() else {
+        return Ok(res);
+    };
+    if let Some(&(extra, text)) = lines.get(1) {
+        return Err(ParseError::new(extra, 1, text, "expected all ranges on a single line"));
+    }
+    let parts: Vec<&str> = line.trim_end().split(',').collect();
+    for part in parts{
+        let Some((start, end)) = part.split_once('-') else {
+            return Err(ParseError::at(i, line, part, "expected a range like 11-22"));
+        };
+        let start = parse_int(i, line, start)?;
+        let end = parse_int(i, line, end)?;
+        res.push( Interval { start, end } );
+    }
+    Ok(res)
+}
+
+/// Whether `s` is made only of repetitions of `sub`, at most two unless `ignore_count`.
+pub fn check_occurences(s: &str, sub: &str, ignore_count : bool) -> bool {
+    // this checks if the entire string consists of recurring occurrences of sub
+    if !s.len().is_multiple_of(sub.len()) {
+        return false;   
+    }
+    let mut pos = 0;
+    let mut rep_cnt = 0;
+    while pos < s.len() {
+        if &s[pos..pos+sub.len()] != sub {
+            return false;   
+        }
+        pos += sub.len();
+        rep_cnt += 1;
+        if rep_cnt > 2 && !ignore_count{
+            return false;   
+        }
+    }
+    true
+}
+
+/// Sums the invalid IDs in the interval.
+pub fn check_interval(interval: &Interval, ignore_count : bool) -> i64 {
+    let mut sum : i64 = 0;
+    for i in interval.start..=interval.end {
+        let i_str = i.to_string();
+        for j in 1..=i_str.len()/2 {
+            if check_occurences(&i_str, &i_str[0..j], ignore_count) {
+                sum += i;
+                break;
+            }
+        }
+    }
+    sum
+}
+
+/// Sums the IDs made of a sequence repeated twice.
+pub fn part_1(intervals: &[Interval]) -> i64 {
+    let mut ans : i64 = 0;
+    for i in intervals.iter() {
+        ans += check_interval(i,false);
+    }
+    ans
+}
+
+/// Sums the IDs made of a sequence repeated at least twice.
+pub fn part_2(intervals: &[Interval]) -> i64 {
+    let mut ans : i64 = 0;
+    for i in intervals.iter() {
+        ans += check_interval(i,true);
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example;
+
+    #[test]
+    fn test_parse_demo_file() {
+        let result = parse("11-22,95-115").unwrap();
+        
+        assert_eq!(result.len(), 2);
+        assert_eq!(result, vec![
+            Interval { start: 11, end: 22 },
+            Interval { start: 95, end: 115 }
+        ]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("11-22,95115").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, "95115"));
+
+        let err = parse("11-22,95-1x5").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (10, "1x5"));
+
+        let err = parse("11-22\n95-115").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "95-115"));
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let example = example!(1);
+        let expected = parse(example).unwrap();
+        assert_eq!(parse(&format!("{}\n", example)).unwrap(), expected);
+        assert_eq!(parse(&format!("{}\r\n", example.replace('\n', "\r\n"))).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_check_occurence() {
+        let test_str = "1188511885";
+        let result = check_occurences(test_str, "11885",false);
+        assert!(result);
+    }
+
+    #[test]
+    fn test_check_interval() {
+        let test_interval = Interval { start: 11, end: 22 };
+        let result = check_interval(&test_interval,false);
+        assert_eq!(result, 33);
+    }
+
+    aoc_common::answer_tests!(Day02);
+}
@@ -0,0 +1,31 @@
+use aoc_common::Rng;
+
+/// Batteries in every generated bank, as in the puzzle input.
+pub const BANK_LEN: usize = 100;
+
+/// `size` banks of `BANK_LEN` batteries with joltages 1 to 9.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..BANK_LEN {
+            input.push(char::from(b'0' + rng.range(1..=9) as u8));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated::<Day03>(&[0, 1, 200], &Params::defaults(Day03::PARAMS));
+        let banks = crate::parse(&generate(3, &mut Rng::new(1))).unwrap();
+        assert!(banks.len() == 3 && banks.iter().all(|bank| bank.len() == BANK_LEN && !bank.contains('0')));
+    }
+}
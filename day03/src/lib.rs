@@ -0,0 +1,145 @@
+//! Day 3: Lobby
+
+use aoc_common::{non_blank_lines, Answer, ParamSpec, Params, ParseError, Rng, Solution};
+
+pub mod generate;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const PARAMS: &'static [ParamSpec] = &[
+        ("part1_batteries", 2, 1..=MAX_BATTERIES as i64),
+        ("part2_batteries", 12, 1..=MAX_BATTERIES as i64),
+    ];
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(lines: &Vec<String>, params: &Params) -> Result<Answer, String> {
+        Ok(total_joltage(lines, params.get("part1_batteries"))?.into())
+    }
+
+    fn part_2(lines: &Vec<String>, params: &Params) -> Result<Answer, String> {
+        Ok(total_joltage(lines, params.get("part2_batteries"))?.into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Most batteries whose joltage always fits in a `u64`.
+pub const MAX_BATTERIES: usize = 19;
+
+/// Parses one battery bank per line.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    non_blank_lines(input)
+        .into_iter()
+        .map(|(i, line)| match line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            Some((pos, c)) => Err(ParseError::at(i, line, &line[pos..pos + c.len_utf8()], "expected a digit")),
+            None => Ok(line.to_string()),
+        })
+        .collect()
+}
+
+/// Largest number formed by turning on `battery_len` batteries of the bank, in order.
+///
+/// Fails if the bank has fewer batteries, or if the joltage would not fit in a `u64`.
+pub fn find_joltage(line: &str, battery_len: usize) -> Result<u64, String> {
+    let chars: Vec<i32> = line
+        .chars()
+        .filter_map(|c| c.to_digit(10).map(|d| d as i32))
+        .collect();
+    if chars.len() < battery_len {
+        return Err(format!("bank {} has {} batteries, expected at least {}", line, chars.len(), battery_len));
+    }
+    if battery_len > MAX_BATTERIES {
+        return Err(format!("{} batteries do not fit a u64 joltage, at most {} do", battery_len, MAX_BATTERIES));
+    }
+
+    let mut joltage : u64 = 0;
+    let mut max_pos_prev = 0;
+    for j in 1..=battery_len {
+        let (max_pos, first_max) = chars[max_pos_prev..chars.len() - battery_len + j]
+            .iter()
+            .enumerate()
+            .fold((0, 0), |(best_i, best_val), (i, &val)| {
+                if val > best_val {
+                    (i + max_pos_prev, val)
+                } else {
+                    (best_i, best_val)
+                }
+            });
+        joltage += first_max as u64 * 10_u64.pow((battery_len - j) as u32);
+        max_pos_prev = max_pos + 1;
+    }
+    Ok(joltage)
+}
+
+/// Total joltage with `battery_len` batteries per bank: two for part 1, twelve for part 2.
+pub fn total_joltage(lines: &[String], battery_len: usize) -> Result<u128, String> {
+    let mut ans = 0;
+    for line in lines {
+        ans += find_joltage(line, battery_len)? as u128;
+    }
+    Ok(ans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, Part};
+
+    #[test]
+    fn test_parse_demo_file() {
+        let result = parse("123\n456\n789").unwrap();
+        
+        assert_eq!(result.len(), 3);
+        assert_eq!(result, vec!["123", "456", "789"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("123\n45x6").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+    }
+
+    #[test]
+    fn test_find_joltage_part1() {
+        let mut result = find_joltage("987654321111111",2).unwrap();
+        assert_eq!(result,98);
+        result = find_joltage("811111111111119",2).unwrap();
+        assert_eq!(result,89);
+        result = find_joltage("234234234234278",2).unwrap();
+        assert_eq!(result,78);      
+        result = find_joltage("818181911112111",2).unwrap();
+        assert_eq!(result,92);                  
+    }
+
+    #[test]
+    fn test_find_joltage_part2() {
+        let mut result = find_joltage("987654321111111",12).unwrap();
+        assert_eq!(result,987654321111);
+        result = find_joltage("811111111111119",12).unwrap();
+        assert_eq!(result,811111111119);
+        result = find_joltage("234234234234278",12).unwrap();
+        assert_eq!(result,434234234278);      
+        result = find_joltage("818181911112111",12).unwrap();
+        assert_eq!(result,888911112111);                  
+    }
+
+    #[test]
+    fn test_find_joltage_errors() {
+        assert_eq!(find_joltage("123", 12), Err("bank 123 has 3 batteries, expected at least 12".to_string()));
+        assert_eq!(find_joltage("123", 3), Ok(123));
+        assert!(find_joltage(&"9".repeat(25), 20).is_err());
+        assert_eq!(find_joltage(&"9".repeat(25), 19), Ok(9_999_999_999_999_999_999));
+        assert!(solve::<Day03>("987654321111111\n123\n", &Part::ALL, &Params::defaults(Day03::PARAMS)).is_err());
+    }
+
+    aoc_common::answer_tests!(Day03);
+}
@@ -0,0 +1,28 @@
+use aoc_common::Rng;
+
+/// A `size` by `size` grid in which about two thirds of the cells hold a roll.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(if rng.chance(2.0 / 3.0) { '@' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated::<Day04>(&[1, 2, 40], &Params::defaults(Day04::PARAMS));
+        let grid = crate::parse(&generate(5, &mut Rng::new(1))).unwrap();
+        assert!(grid.len() == 5 && grid.iter().all(|row| row.len() == 5));
+    }
+}
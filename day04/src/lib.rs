@@ -0,0 +1,134 @@
+//! Day 4: Printing Department
+
+use aoc_common::{lines, Answer, ParamSpec, Params, ParseError, Rng, Solution};
+
+pub mod generate;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const PARAMS: &'static [ParamSpec] = &[("neighbour_limit", 4, 0..=9)];
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(lines: &Vec<Vec<char>>, params: &Params) -> Result<Answer, String> {
+        Ok(part_1(lines, params.get("neighbour_limit")).into())
+    }
+
+    fn part_2(lines: &Vec<Vec<char>>, params: &Params) -> Result<Answer, String> {
+        Ok(part_2(lines, params.get("neighbour_limit")).into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Parses the grid of paper rolls.
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let lines = lines(input);
+    let width = lines.first().map_or(0, |line| line.len());
+    lines.into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if let Some((pos, c)) = line.char_indices().find(|&(_, c)| c != '@' && c != '.') {
+                return Err(ParseError::at(i, line, &line[pos..pos + c.len_utf8()], "expected @ or ."));
+            }
+            if line.len() != width {
+                return Err(ParseError::new(i, 1, line, format!("expected a row of {} cells", width)));
+            }
+            Ok(line.chars().collect())
+        })
+        .collect()
+}
+
+/// Whether the roll at (x, y) has fewer than `limit` neighbouring rolls.
+pub fn check_surrounding_rolls(lines :&[Vec<char>], x : usize, y: usize, limit: usize) -> bool {
+    let mut cnt = 0;
+    for dx in -1..=1 {
+        for dy in -1..=1 {
+            let nx = x as isize + dx;
+            let ny = y as isize + dy;
+            if nx < 0 || ny < 0 || ny as usize >= lines.len() || nx as usize >= lines[0].len()
+                || (dx == 0 && dy == 0) {
+                continue;
+            }
+            if lines[ny as usize][nx as usize] == '@' {
+                cnt += 1;
+            }
+        }
+    }
+    if cnt < limit {
+        return true;
+    }
+    false
+}
+
+/// Counts the rolls a forklift can reach, those with fewer than `limit` (four) neighbours.
+pub fn part_1(lines: &[Vec<char>], limit: usize) -> i32 {
+    let mut ans = 0;
+
+    for y in 0..lines.len() {
+        for x in 0..lines[0].len() {
+            if lines[y][x] == '@' && check_surrounding_rolls(lines,x,y,limit) {
+                ans += 1;
+            }
+        }
+    }
+    ans
+}
+
+/// Counts the rolls removed by repeatedly taking every reachable roll.
+pub fn part_2(lines: &[Vec<char>], limit: usize) -> i32 {
+    let mut lines = lines.to_vec();
+    let mut rolls_collected: Vec<(usize,usize)> = Vec::new();
+    let mut ans = 0;
+    let mut rolls_removed = true;
+
+    while rolls_removed {
+        rolls_removed = false;
+        rolls_collected.clear();
+        for y in 0..lines.len() {
+            for x in 0..lines[0].len() {
+                if lines[y][x] == '@' && check_surrounding_rolls(&lines,x,y,limit) {
+                    rolls_collected.push((x,y));
+                    ans += 1;
+                    rolls_removed = true;
+                }
+            }
+        }
+        for (x,y) in &rolls_collected {
+            lines[*y][*x] = '.';
+        }
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_demo_file() {
+        let result = parse("..@\n@@@\n..@").unwrap();
+        
+        assert_eq!(result.len(), 3);
+        assert_eq!(result, vec![vec!['.', '.', '@'], vec!['@', '@', '@'], vec!['.', '.', '@']]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("..@\n@x@").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+        let err = parse("..@\n@@").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    aoc_common::answer_tests!(Day04);
+}
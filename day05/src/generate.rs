@@ -0,0 +1,43 @@
+use aoc_common::Rng;
+
+/// Fresh IDs and ingredient IDs stay below this, as in the puzzle input.
+const MAX_ID: u64 = 999_999_999_999_999;
+
+/// `size` ranges of fresh IDs, then `size` ingredient IDs of which about half
+/// fall into one of the ranges.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let ranges: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let low = rng.range(1..=MAX_ID - 1_000_000_000_000);
+            (low, low + rng.range(0..=1_000_000_000_000))
+        })
+        .collect();
+    let mut input: String = ranges.iter().map(|(low, high)| format!("{}-{}\n", low, high)).collect();
+    input.push('\n');
+    for _ in 0..size {
+        let id = if rng.chance(0.5) {
+            let &(low, high) = rng.pick(&ranges);
+            rng.range(low..=high)
+        } else {
+            rng.range(1..=MAX_ID)
+        };
+        input.push_str(&format!("{}\n", id));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated::<Day05>(&[1, 10, 200], &Params::defaults(Day05::PARAMS));
+        let (ranges, ids) = crate::parse(&generate(4, &mut Rng::new(1))).unwrap();
+        assert_eq!((ranges.len(), ids.len()), (4, 4));
+        assert!(ranges.iter().all(|(low, high)| low <= high));
+    }
+}
@@ -0,0 +1,174 @@
+//! Day 5: Cafeteria
+
+use aoc_common::{parse_int, sections, Answer, Params, ParseError, Rng, Solution};
+use aoc_common::tracing::{debug, trace};
+
+pub mod generate;
+
+/// Fresh ID intervals and the available ingredient IDs.
+pub type Inventory = (Vec<(u64,u64)>, Vec<u64>);
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Inventory, ParseError> {
+        parse(input)
+    }
+
+    fn part_1((intervals, ingredients): &Inventory, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(intervals, ingredients).into())
+    }
+
+    fn part_2((intervals, _): &Inventory, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(intervals).into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Parses the fresh ID intervals and, after the blank line, the ingredient IDs.
+pub fn parse(input: &str) -> Result<Inventory, ParseError> {
+    let mut intervals: Vec<(u64,u64)> = Vec::new();
+    let mut ingredients: Vec<u64> = Vec::new();
+    let sections = sections(input);
+    if let Some(&(i, line)) = sections.get(2).and_then(|s| s.first()) {
+        return Err(ParseError::new(i, 1, line, "expected only ranges and ingredient IDs"));
+    }
+    let mut sections = sections.into_iter();
+    for (i, line) in sections.next().unwrap_or_default() {
+        let line = line.trim_end();
+        let Some((low, high)) = line.split_once('-') else {
+            return Err(ParseError::at(i, line, line, "expected a range like 3-5"));
+        };
+        let (low_id, high_id): (u64, u64) = (parse_int(i, line, low)?, parse_int(i, line, high)?);
+        if low_id > high_id {
+            return Err(ParseError::at(i, line, high, format!("expected the range to end at {} or later", low_id)));
+        }
+        intervals.push((low_id, high_id));
+    }
+    for (i, line) in sections.next().unwrap_or_default() {
+        let line = line.trim_end();
+        ingredients.push(parse_int(i, line, line)?);
+    }
+    Ok((intervals, ingredients))
+}
+
+/// Counts the ingredients that fall into any of the fresh intervals.
+pub fn part_1(intervals: &[(u64,u64)], ingredients: &[u64]) -> u64 {
+    let mut ans = 0;
+    for &ingredient in ingredients {
+        if intervals.iter().any(|(low,high)| ingredient >= *low && ingredient <= *high) {
+            ans += 1;
+        }
+    }
+    ans
+}
+
+/// Merges overlapping intervals; the result is sorted and disjoint.
+pub fn merge_intervals(intervals: &[(u64,u64)]) -> Vec<(u64,u64)> {
+    let mut intervals = intervals.to_vec();
+
+    let mut merged_intervals: Vec<(u64,u64)> = Vec::new();
+    let mut merged = true;
+
+    while merged {
+        merged = false;
+        merged_intervals.clear();
+        for interval in &intervals {
+            let mut new_low = interval.0;
+            let mut new_high = interval.1;
+            for interval_cross in &intervals {
+                if interval == interval_cross {
+                    continue;
+                }
+                if interval.0 <= interval_cross.1 && interval.0 >= interval_cross.0 {
+                    new_low = interval_cross.0;
+                    merged = true;
+                }
+                if interval.1 >= interval_cross.0 && interval.1 <= interval_cross.1 {
+                    new_high = interval_cross.1;
+                    merged = true;
+                }
+            }
+            merged_intervals.push((new_low,new_high));
+        }
+        merged_intervals.sort();
+        merged_intervals.dedup();
+        intervals = merged_intervals.clone();
+    }
+    merged_intervals
+}
+
+/// Counts the IDs covered by the fresh intervals.
+pub fn part_2(intervals: &[(u64,u64)]) -> u128 {
+    let merged_intervals = merge_intervals(intervals);
+
+    debug!(count = merged_intervals.len(), "merged intervals");
+    for (low, high) in &merged_intervals {
+        trace!(low, high, "merged interval");
+    }
+
+    merged_intervals
+        .iter()
+        .map(|(low,high)| (high - low) as u128 + 1)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example;
+
+    #[test]
+    fn test_parse_file() {
+        let (intervals, ingredients) = parse("11-12\n21-22\n\n7\n8\n9").unwrap();
+        
+        assert_eq!(intervals.len(), 2);
+        assert_eq!(intervals, vec![(11, 12), (21, 22)]);
+        assert_eq!(ingredients.len(), 3);
+        assert_eq!(ingredients, vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("11-12\n2122\n\n7").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "2122"));
+
+        let err = parse("11-12\n\n7\n8x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 1, "8x"));
+
+        let err = parse("11-12\n\n7\n\n8").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (5, "8"));
+
+        let err = parse("11-12\n5-3\n\n7").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "3"));
+        assert_eq!(err.message, "expected the range to end at 5 or later");
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let example = example!(1);
+        let expected = parse(example).unwrap();
+        assert_eq!(parse(&format!("{}\n", example)).unwrap(), expected);
+        assert_eq!(parse(&format!("{}\r\n", example.replace('\n', "\r\n"))).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_merge_intervals() {
+        let result = merge_intervals(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(result, vec![(3, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn test_part_2_full_range() {
+        assert_eq!(part_2(&[(0, u64::MAX), (4, 4)]), u64::MAX as u128 + 1);
+    }
+
+    aoc_common::answer_tests!(Day05);
+}
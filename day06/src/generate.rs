@@ -0,0 +1,42 @@
+use aoc_common::Rng;
+
+/// A worksheet of `size` problems side by side, each of two to four numbers
+/// (the same count for every problem) with up to four digits, aligned to the
+/// left or the right of the problem's column.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let rows = rng.range(2..=4) as usize;
+    let mut lines = vec![Vec::new(); rows + 1];
+    for _ in 0..size {
+        let width = rng.range(1..=4) as u32;
+        let left = rng.chance(0.5);
+        // One number uses the whole width, so no column of the problem is empty.
+        let widest = rng.range(0..=rows as u64 - 1) as usize;
+        for (row, line) in lines.iter_mut().take(rows).enumerate() {
+            let digits = if row == widest { width } else { rng.range(1..=width as u64) as u32 };
+            let number = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+            line.push(if left {
+                format!("{:<1$}", number, width as usize)
+            } else {
+                format!("{:>1$}", number, width as usize)
+            });
+        }
+        let operator = if rng.chance(0.5) { '*' } else { '+' };
+        lines[rows].push(format!("{:<1$}", operator, width as usize));
+    }
+    lines.iter().map(|cells| cells.join(" ") + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated::<Day06>(&[1, 10, 1000], &Params::defaults(Day06::PARAMS));
+        let worksheet = crate::parse(&generate(6, &mut Rng::new(1))).unwrap();
+        assert_eq!((worksheet.by_rows.len(), worksheet.by_columns.len()), (6, 6));
+    }
+}
@@ -0,0 +1,258 @@
+//! Day 6: Trash Compactor
+
+use aoc_common::{lines, parse_int_fields_whitespace, Answer, Params, ParseError, Rng, Solution};
+
+pub mod generate;
+
+/// Numbers of one problem and the operator, `*` or `+`, that combines them.
+pub type Problem = (Vec<u64>,char);
+
+/// The problems of the worksheet, read both ways.
+#[derive(Debug, PartialEq)]
+pub struct Worksheet {
+    pub by_rows: Vec<Problem>,
+    pub by_columns: Vec<Problem>,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Worksheet, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(worksheet: &Worksheet, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(&worksheet.by_rows)?.into())
+    }
+
+    fn part_2(worksheet: &Worksheet, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(&worksheet.by_columns)?.into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Reads the worksheet both row by row and column by column.
+pub fn parse(input: &str) -> Result<Worksheet, ParseError> {
+    let lines: Vec<String> = lines(input).into_iter().map(|s| s.to_string()).collect();
+    if lines.is_empty() {
+        return Err(ParseError::new(0, 1, "", "expected a line of operators"));
+    }
+    Ok(Worksheet { by_rows: parse_part1(&lines)?, by_columns: parse_part2(&lines)? })
+}
+
+fn is_operator_line(line: &str) -> bool {
+    line.trim_start().starts_with(['*', '+'])
+}
+
+fn parse_operators(i: usize, line: &str) -> Result<Vec<char>, ParseError> {
+    line.split_whitespace()
+        .map(|s| match s {
+            "*" | "+" => Ok(s.chars().next().unwrap()),
+            _ => Err(ParseError::at(i, line, s, "expected * or +")),
+        })
+        .collect()
+}
+
+/// Reads the numbers of each problem row by row.
+pub fn parse_part1(lines: &[String]) -> Result<Vec<Problem>, ParseError> {
+    let mut result: Vec<Problem> = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            return Err(ParseError::new(i, 1, line, "unexpected empty line"));
+        }
+        if is_operator_line(line) {
+            let chars = parse_operators(i, line)?;
+            if chars.len() != result.len() {
+                return Err(ParseError::new(i, 1, line, format!("expected {} operators", result.len())));
+            }
+            for (i,ch) in chars.iter().enumerate() {
+                result[i].1 = *ch;
+            }
+        } else {
+            let numbers : Vec<u64> = parse_int_fields_whitespace(i, line)?;
+            
+            for (i,num) in numbers.iter().enumerate() {
+                if i >= result.len() {
+                    result.push((Vec::new(), ' '));
+                }
+                result[i].0.push(*num);
+            }
+        }
+    }
+    if result.iter().any(|problem| problem.1 == ' ') {
+        let last = lines.len() - 1;
+        return Err(ParseError::new(last, 1, &lines[last], "expected a line of operators"));
+    }
+    Ok(result)
+}
+
+/// Reads the numbers of each problem column by column.
+pub fn parse_part2(lines: &[String]) -> Result<Vec<Problem>, ParseError> {
+    let mut lines = lines.to_vec();
+    let last = lines.len() - 1;
+    if last == 0 || !is_operator_line(&lines[last]) {
+        return Err(ParseError::new(last, 1, &lines[last], "expected a line of operators"));
+    }
+
+    let mut result: Vec<Problem> = parse_operators(last, &lines[last])?
+        .into_iter()
+        .map(|ch| (Vec::new(), ch))
+        .collect();
+    lines.remove(last);
+
+    // Columns are counted in chars, as the rows are indexed by them.
+    let max_len = 
+        lines.iter()
+            .map(|s| { s.chars().count() })
+            .max().unwrap();
+
+    let chars : Vec<Vec<char>> = 
+        lines.iter().map(|s| {
+            let mut clone: Vec<char> = s.chars().collect();
+            clone.resize(max_len, ' ');
+            clone
+        } ).collect();
+
+    let mut problem = 0;
+    for x in 0..max_len {
+        let mut num_str = String::new();
+        let mut top = 0;
+        for (y, row) in chars.iter().enumerate() {
+            if row[x] != ' ' {
+                if !row[x].is_ascii_digit() {
+                    return Err(ParseError::new(y, x + 1, &row[x].to_string(), "expected a digit"));
+                }
+                if num_str.is_empty() {
+                    top = y;
+                }
+                num_str.push(row[x]);
+            }
+        }
+        if num_str.is_empty() {
+            problem += 1;
+            continue;
+        }
+        if problem >= result.len() {
+            return Err(ParseError::new(last, 1, "", format!("expected {} operators", problem + 1)));
+        }
+        let number = num_str
+            .parse::<u64>()
+            .map_err(|_| ParseError::new(top, x + 1, &chars[top][x].to_string(), "expected a number that fits in 64 bits"))?;
+        result[problem].0.push(number);
+    }
+
+    Ok(result)
+}
+
+/// Sum of the answers to `problems`; fails if it does not fit in 128 bits.
+pub fn grand_total(problems: &[Problem]) -> Result<u128, String> {
+    let mut ans : u128 = 0;
+    for problem in problems{
+        let answer = problem.0.iter().try_fold(
+            if problem.1 == '*' { 1 } else { 0 },
+            |acc : u128, &x| {
+                if problem.1 == '*' {
+                    acc.checked_mul(x as u128)
+                } else {
+                    acc.checked_add(x as u128)
+                }
+        });
+        ans = answer
+            .and_then(|answer| ans.checked_add(answer))
+            .ok_or("the grand total overflows 128 bits")?;
+    }
+    Ok(ans)
+}
+
+/// Grand total of the problems read row by row.
+pub fn part_1(problems: &[Problem]) -> Result<u128, String> {
+    grand_total(problems)
+}
+
+/// Grand total of the problems read column by column.
+pub fn part_2(problems: &[Problem]) -> Result<u128, String> {
+    grand_total(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example;
+
+    #[test]
+    fn test_parse_part1_file() {
+        let result = Day06::parse(example!(1)).unwrap().by_rows;
+        
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[0], (vec![123, 45, 6], '*'));
+        assert_eq!(result[1], (vec![328, 64, 98], '+'));
+        assert_eq!(result[2], (vec![51, 387, 215], '*'));
+        assert_eq!(result[3], (vec![64, 23, 314], '+'));
+    }
+
+    #[test]
+    fn test_parse_part2_file() {
+        let result = Day06::parse(example!(1)).unwrap().by_columns;
+        
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[0], (vec![1, 24, 356], '*'));
+        assert_eq!(result[1], (vec![369, 248, 8], '+'));        
+        assert_eq!(result[2], (vec![32, 581, 175], '*')); 
+        assert_eq!(result[3], (vec![623, 431, 4], '+'));   
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("12 3\n4 5x\n*  +").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "5x"));
+
+        let err = parse("12 3\n4 5\n*  -").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "-"));
+
+        let err = parse("12 3\n4 5").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "expected a line of operators");
+
+        let err = parse("12 3\n\n*  +").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        assert!(parse("\n").is_err());
+
+        let rows: Vec<String> = ["1é", "234", "*"].iter().map(|s| s.to_string()).collect();
+        let err = parse_part2(&rows).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "é"));
+
+        let rows: Vec<String> = std::iter::repeat_n("9", 21).chain(["*"]).map(|s| s.to_string()).collect();
+        let err = parse_part2(&rows).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let example = example!(1);
+        let expected = parse(example).unwrap();
+        assert_eq!(parse(&format!("{}\n", example)).unwrap(), expected);
+        assert_eq!(parse(&format!("{}\r\n", example.replace('\n', "\r\n"))).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_grand_total_overflow() {
+        let max = u64::MAX as u128;
+        let problems: Vec<Problem> = vec![(vec![u64::MAX, u64::MAX], '*')];
+        assert_eq!(part_1(&problems), Ok(max * max));
+        let problems: Vec<Problem> = vec![(vec![u64::MAX; 3], '*')];
+        assert_eq!(part_1(&problems), Err("the grand total overflows 128 bits".to_string()));
+        let problems: Vec<Problem> = vec![(vec![u64::MAX, u64::MAX], '*'), (vec![u64::MAX; 3], '+')];
+        assert!(part_2(&problems).is_err());
+    }
+
+    aoc_common::answer_tests!(Day06);
+}
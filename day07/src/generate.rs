@@ -0,0 +1,43 @@
+use aoc_common::Rng;
+
+/// A manifold `2 * size + 1` cells wide with the start in the middle and `size`
+/// rows of splitters, every row of splitters after an empty row.
+///
+/// Splitters get sparser as the manifold grows, so the number of timelines
+/// stays well within a `u64` whatever the size.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let width = 2 * size + 1;
+    let density = (40.0 / size.max(1) as f64).min(0.3);
+    let empty = ".".repeat(width) + "\n";
+    let mut input = format!("{}S{}\n", ".".repeat(size), ".".repeat(size));
+    for _ in 0..size {
+        input.push_str(&empty);
+        let mut row = String::from(".");
+        for _ in 1..width - 1 {
+            // Splitters are never side by side, nor on the edge.
+            let split = !row.ends_with('^') && rng.chance(density);
+            row.push(if split { '^' } else { '.' });
+        }
+        if width > 1 {
+            row.push('.');
+        }
+        input.push_str(&row);
+        input.push('\n');
+    }
+    input + &empty
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated::<Day07>(&[0, 1, 70, 1000], &Params::defaults(Day07::PARAMS));
+        let manifold = crate::parse(&generate(3, &mut Rng::new(1))).unwrap();
+        assert_eq!((manifold.len(), manifold[0].len()), (8, 7));
+    }
+}
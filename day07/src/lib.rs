@@ -0,0 +1,177 @@
+//! Day 7: Laboratories
+
+use aoc_common::{lines, Answer, Params, ParseError, Rng, Solution};
+use aoc_common::tracing::{debug, trace};
+
+pub mod generate;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(manifold: &Vec<Vec<char>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(manifold).into())
+    }
+
+    fn part_2(manifold: &Vec<Vec<char>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(manifold)?.into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Parses the tachyon manifold diagram.
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let lines = lines(input);
+    let first = lines.first().copied().unwrap_or_default();
+    if !first.contains('S') {
+        return Err(ParseError::new(0, 1, first, "expected the start S on the first line"));
+    }
+    let width = first.len();
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            if let Some((pos, c)) = s.char_indices().find(|&(_, c)| !".S^".contains(c)) {
+                return Err(ParseError::at(i, s, &s[pos..pos + c.len_utf8()], "expected ., S or ^"));
+            }
+            if s.len() != width {
+                return Err(ParseError::new(i, 1, s, format!("expected a row of {} cells", width)));
+            }
+            if s.starts_with('^') || s.ends_with('^') {
+                let pos = if s.starts_with('^') { 0 } else { width - 1 };
+                return Err(ParseError::at(i, s, &s[pos..pos + 1], "splitter on the edge of the manifold"));
+            }
+            Ok(s.chars().collect())
+        })
+        .collect()
+}
+
+/// Counts how many times the beam is split.
+pub fn part_1(manifold: &[Vec<char>]) -> u64 {
+    let mut manifold = manifold.to_vec();
+    let start_x = manifold[0].iter().position(|c| *c == 'S').unwrap();
+
+    manifold[0][start_x] = '|';
+    let mut split_count = 0;
+    for y in 1..manifold.len() {
+        for x in 0..manifold[y].len() {
+            if manifold[y-1][x] != '|' {
+                continue;
+            }
+            if manifold[y][x] == '^' {
+                split_count += 1;
+                manifold[y][x-1] = '|';
+                manifold[y][x+1] = '|';
+            } else {
+                manifold[y][x] = '|';
+            }
+        }
+    }
+
+    for (y, row) in manifold.iter().enumerate() {
+        trace!(y, row = %row.iter().collect::<String>(), "beams");
+    }
+    debug!(split_count, "beam traced");
+
+    split_count
+}
+
+/// Counts the timelines a single particle ends up in; fails if they do not fit in 128 bits.
+pub fn part_2(manifold: &[Vec<char>]) -> Result<u128, String> {
+    let mut manifold = manifold.to_vec();
+    let mut multiverse_count : Vec<Vec<u128>> = vec![vec![0;manifold[0].len()];manifold.len()];
+    let start_x = manifold[0].iter().position(|c| *c == 'S').unwrap();
+
+    manifold[0][start_x] = '|';
+    multiverse_count[0][start_x] = 1;
+    for y in 1..manifold.len() {
+        for x in 0..manifold[y].len() {
+            if manifold[y-1][x] != '|' {
+                continue;
+            }
+            let count = multiverse_count[y-1][x];
+            let targets = if manifold[y][x] == '^' { vec![x-1, x+1] } else { vec![x] };
+            for target in targets {
+                manifold[y][target] = '|';
+                multiverse_count[y][target] = multiverse_count[y][target]
+                    .checked_add(count)
+                    .ok_or("the timeline count overflows 128 bits")?;
+            }
+        }
+    }
+
+    for (y, counts) in multiverse_count.iter().enumerate() {
+        trace!(y, ?counts, "timelines");
+    }
+
+    multiverse_count[multiverse_count.len()-1]
+        .iter()
+        .try_fold(0u128, |acc, &count| acc.checked_add(count))
+        .ok_or_else(|| "the timeline count overflows 128 bits".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example;
+
+    #[test]
+    fn test_parse_demo_file() {
+        let result = parse(".S.\n.^.").unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result, vec![['.','S','.'],['.','^','.']]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("...\n.^.").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+
+        let err = parse(".S.\n.v.").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "v"));
+
+        let err = parse(".S.\n..^").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "^"));
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let example = example!(1);
+        let expected = parse(example).unwrap();
+        assert_eq!(parse(&format!("{}\n", example)).unwrap(), expected);
+        assert_eq!(parse(&format!("{}\r\n", example.replace('\n', "\r\n"))).unwrap(), expected);
+    }
+
+    /// A manifold that splits every beam on each of its `rows` rows of splitters.
+    fn pascal(rows: usize) -> Vec<Vec<char>> {
+        let width = 2 * rows + 3;
+        let mut manifold = vec![vec!['.'; width]; rows + 1];
+        manifold[0][rows + 1] = 'S';
+        for (r, row) in manifold.iter_mut().enumerate().skip(1) {
+            for x in (rows + 2 - r..=rows + r).step_by(2) {
+                row[x] = '^';
+            }
+        }
+        manifold
+    }
+
+    #[test]
+    fn test_part_2_overflow() {
+        assert_eq!(part_2(&pascal(3)), Ok(8));
+        assert_eq!(part_2(&pascal(127)), Ok(1 << 127));
+        assert_eq!(part_2(&pascal(128)), Err("the timeline count overflows 128 bits".to_string()));
+    }
+
+    aoc_common::answer_tests!(Day07);
+}
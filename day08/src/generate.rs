@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+/// `size` junction boxes at distinct positions with coordinates below 100 000.
+///
+/// Part 1 needs three circuits left after its connections, and each connection
+/// joins at most two circuits, so `size` boxes are always enough for `size - 3`
+/// connections; the puzzle's 1000 need 1003.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+    while seen.len() < size {
+        let coord = (rng.range(0..=99_999), rng.range(0..=99_999), rng.range(0..=99_999));
+        if seen.insert(coord) {
+            input.push_str(&format!("{},{},{}\n", coord.0, coord.1, coord.2));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+    use aoc_common::random::assert_generated;
+    use aoc_common::{Params, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        let mut params = Params::defaults(Day08::PARAMS);
+        for size in [3, 20, 200] {
+            params.set("connections", size as i64 - 3).unwrap();
+            assert_generated::<Day08>(&[size], &params);
+        }
+        assert_eq!(crate::parse(&generate(50, &mut Rng::new(1))).unwrap().len(), 50);
+    }
+}
@@ -0,0 +1,238 @@
+//! Day 8: Playground
+
+use aoc_common::{non_blank_lines, parse_int_fields, Answer, ParamSpec, Params, ParseError, Rng, Solution};
+use aoc_common::tracing::{debug, trace};
+
+pub mod generate;
+
+/// Position of a junction box.
+#[derive(PartialEq, Debug, Clone, Hash, Copy)]
+pub struct Coord {
+    pub x : u64,
+    pub y : u64,
+    pub z : u64
+}
+
+/// Two junction boxes; equal regardless of order.
+#[derive(Clone, Copy, Debug)]
+pub struct CoordPair {
+    pub c1 : Coord,
+    pub c2 : Coord
+}
+
+impl CoordPair {
+    /// Straight-line distance between the two boxes.
+    pub fn dist(&self) -> f64 {
+        let dx = self.c1.x.abs_diff(self.c2.x) as f64;
+        let dy = self.c1.y.abs_diff(self.c2.y) as f64;
+        let dz = self.c1.z.abs_diff(self.c2.z) as f64;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+impl PartialEq for CoordPair {
+    fn eq(&self, other: &Self) -> bool {
+        (self.c1 == other.c1 && self.c2 == other.c2) ||
+        (self.c1 == other.c2 && self.c2 == other.c1)
+    }
+}
+
+impl Eq for CoordPair {}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const PARAMS: &'static [ParamSpec] = &[("connections", 1000, 0..=u32::MAX as i64)];
+
+    type Input = Vec<Coord>;
+
+    fn parse(input: &str) -> Result<Vec<Coord>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(boxes: &Vec<Coord>, params: &Params) -> Result<Answer, String> {
+        Ok(part_1(boxes, params.get("connections"))?.into())
+    }
+
+    fn part_2(boxes: &Vec<Coord>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(boxes).into())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Parses one junction box position per line.
+pub fn parse(input: &str) -> Result<Vec<Coord>, ParseError> {
+    non_blank_lines(input)
+        .iter()
+        .map(|&(i, s)| {
+            let nums: Vec<u64> = parse_int_fields(i, s, ',')?;
+            if nums.len() != 3 {
+                return Err(ParseError::new(i, 1, s, "expected three coordinates X,Y,Z"));
+            }
+            Ok(Coord { x: nums[0], y: nums[1], z: nums[2] })
+        })
+        .collect()
+}
+
+/// Every pair of boxes, closest pair first.
+pub fn pairs_by_distance(boxes: &[Coord]) -> Vec<CoordPair> {
+    let mut distances = Vec::new();
+    for i in 0..boxes.len() {
+        for j in (i + 1)..boxes.len() {
+            distances.push(CoordPair { c1: boxes[i], c2: boxes[j] });
+        }
+    }
+
+    distances.sort_by(|a, b| a.dist().partial_cmp(&b.dist()).unwrap());
+    distances
+}
+
+/// Groups of connected boxes; every box starts out in a circuit of its own.
+pub struct Circuits {
+    circuits: Vec<Vec<Coord>>,
+}
+
+impl Circuits {
+    pub fn new(boxes: &[Coord]) -> Circuits {
+        Circuits { circuits: boxes.iter().map(|&b| vec![b]).collect() }
+    }
+
+    /// Joins the circuits of both boxes. Returns false if they were already connected.
+    pub fn connect(&mut self, pair: &CoordPair) -> bool {
+        let mut idx_c1 = 0;
+        let mut idx_c2 = 0;
+        for (i, circuit) in self.circuits.iter().enumerate() {
+            if circuit.contains(&pair.c1) { idx_c1 = i; }
+            if circuit.contains(&pair.c2) { idx_c2 = i; }
+        }
+
+        if idx_c1 == idx_c2 {
+            trace!(c1 = ?pair.c1, c2 = ?pair.c2, "already in the same circuit");
+            return false;
+        }
+        let (target, remove_idx) = if idx_c1 < idx_c2 { (idx_c1, idx_c2) } else { (idx_c2, idx_c1) };
+        let mut to_append = self.circuits.remove(remove_idx);
+        self.circuits[target].append(&mut to_append);
+        trace!(c1 = ?pair.c1, c2 = ?pair.c2, from = remove_idx, to = target, "connected");
+        true
+    }
+
+    pub fn count(&self) -> usize {
+        self.circuits.len()
+    }
+
+    /// Circuit sizes, largest first.
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.circuits.iter().map(|c| c.len()).collect();
+        sizes.sort_by_key(|&s| std::cmp::Reverse(s));
+        sizes
+    }
+}
+
+/// Connects the `connection_count` closest pairs and multiplies the sizes of the three largest circuits.
+///
+/// Fails if the connections leave fewer than three circuits.
+pub fn part_1(boxes: &[Coord], connection_count : usize) -> Result<u64, String> {
+    let distances = pairs_by_distance(boxes);
+    let mut circuits = Circuits::new(boxes);
+
+    for pair in distances.iter().take(connection_count) {
+        circuits.connect(pair);
+    }
+
+    let sizes = circuits.sizes();
+    debug!(circuits = sizes.len(), largest = ?&sizes[..sizes.len().min(3)], "circuit sizes");
+    if sizes.len() < 3 {
+        return Err(format!("{} connections leave {} circuits, expected at least three", connection_count, sizes.len()));
+    }
+    Ok(sizes[..3].iter().map(|&s| s as u64).product())
+}
+
+/// Multiplies the X coordinates of the last pair needed to join every box into one circuit.
+pub fn part_2(boxes: &[Coord]) -> u128 {
+    let distances = pairs_by_distance(boxes);
+    let mut circuits = Circuits::new(boxes);
+    let mut last_pair : Option<CoordPair> = None;
+
+    for pair in &distances {
+        if circuits.count() == 1 {
+            break;
+        }
+        circuits.connect(pair);
+        last_pair = Some(*pair);
+    }
+
+    last_pair.map_or(0, |pair| pair.c1.x as u128 * pair.c2.x as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{example, solve, Part};
+
+    #[test]
+    fn test_parse_dummy_file() {
+        let result = parse("1,2,3\n3,2,1\n0,0,7").unwrap();
+        
+        assert_eq!(result.len(), 3);
+        assert_eq!(result,vec![Coord{x:1,y:2,z:3},Coord{x:3,y:2,z:1},Coord{x:0,y:0,z:7}]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("1,2,3\n3,2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse("1,2,3\n3,-2,1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "-2"));
+    }
+
+    #[test]
+    fn test_part_1_too_few_circuits() {
+        let boxes = parse("0,0,0\n1,0,0\n5,0,0").unwrap();
+        assert_eq!(part_1(&boxes, 0), Ok(1));
+        let err = part_1(&boxes, 1000).unwrap_err();
+        assert_eq!(err, "1000 connections leave 1 circuits, expected at least three");
+        assert!(solve::<Day08>("0,0,0\n1,0,0\n5,0,0\n", &Part::ALL, &Params::defaults(Day08::PARAMS)).is_err());
+
+        // Part 2 needs no third circuit.
+        let boxes = parse("2,0,0\n3,0,0\n").unwrap();
+        assert_eq!(part_1(&boxes, 0), Err("0 connections leave 2 circuits, expected at least three".to_string()));
+        assert_eq!(part_2(&boxes), 6);
+        assert_eq!(part_2(&parse("").unwrap()), 0);
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let example = example!(1);
+        let expected = parse(example).unwrap();
+        assert_eq!(parse(&format!("{}\n", example)).unwrap(), expected);
+        assert_eq!(parse(&format!("{}\r\n", example.replace('\n', "\r\n"))).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_circuits() {
+        let boxes = parse("0,0,0\n1,0,0\n5,0,0\n20,0,0").unwrap();
+        let pairs = pairs_by_distance(&boxes);
+        assert_eq!(pairs[0], CoordPair { c1: boxes[0], c2: boxes[1] });
+
+        let mut circuits = Circuits::new(&boxes);
+        assert!(circuits.connect(&pairs[0]));
+        assert!(circuits.connect(&pairs[1]));
+        assert!(!circuits.connect(&CoordPair { c1: boxes[2], c2: boxes[0] }));
+        assert_eq!(circuits.count(), 2);
+        assert_eq!(circuits.sizes(), vec![3, 1]);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let result = part_1(&Day08::parse(example!(1)).unwrap(),10);
+        assert_eq!(result,Ok(40));
+    }
+
+    aoc_common::answer_tests!(Day08);
+}
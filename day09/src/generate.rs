@@ -0,0 +1,43 @@
+use aoc_common::Rng;
+
+/// The red tiles of a skyline-shaped polygon: columns of random height standing
+/// on a common base, with about `size` tiles (at least four), coordinates below
+/// 100 000, and consecutive tiles in the same row or column.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let columns = (size / 2).max(2) as u64 - 1;
+    let base = rng.range(50_000..=99_999);
+    let max_gap = (99_000 / (columns + 1)).max(1);
+    let mut x = rng.range(1..=max_gap);
+    let mut tiles = vec![(x, base)];
+    let mut height = base;
+    for _ in 0..columns {
+        let previous = height;
+        while height == previous {
+            height = rng.range(1..=base - 1);
+        }
+        tiles.push((x, height));
+        x += rng.range(1..=max_gap);
+        tiles.push((x, height));
+    }
+    tiles.push((x, base));
+    tiles.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use aoc_common::random::assert_generated_parts;
+    use aoc_common::{Params, Part, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        assert_generated_parts::<Day09>(&[0, 4, 500], &[Part::One], &Params::defaults(Day09::PARAMS));
+        let tiles = crate::parse(&generate(10, &mut Rng::new(1))).unwrap();
+        assert_eq!(tiles.len(), 10);
+        for (i, a) in tiles.iter().enumerate() {
+            let b = tiles[(i + 1) % tiles.len()];
+            assert!(a.0 == b.0 || a.1 == b.1, "{:?} and {:?} are not in line", a, b);
+        }
+    }
+}
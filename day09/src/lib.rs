@@ -0,0 +1,103 @@
+//! Day 9: Movie Theater
+
+use aoc_common::{non_blank_lines, parse_int_fields, Answer, Params, ParseError, Rng, Solution};
+
+pub mod generate;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(usize,usize)>;
+
+    fn parse(input: &str) -> Result<Vec<(usize,usize)>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(tiles: &Vec<(usize,usize)>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(tiles).into())
+    }
+
+    fn part_2(_: &Vec<(usize,usize)>, _: &Params) -> Result<Answer, String> {
+        Err("part 2 is not solved yet".to_string())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::generate(size, rng))
+    }
+}
+
+/// Parses the red tile coordinates.
+pub fn parse(input: &str) -> Result<Vec<(usize,usize)>, ParseError> {
+    non_blank_lines(input)
+        .into_iter()
+        .map(|(i, line)| {
+            let nums: Vec<usize> = parse_int_fields(i, line, ',')?;
+            if nums.len() != 2 {
+                return Err(ParseError::new(i, 1, line, "expected two coordinates X,Y"));
+            }
+            Ok((nums[0], nums[1]))
+        })
+        .collect()
+}
+
+/// Largest rectangle with red tiles in two opposite corners; 0 with fewer than two tiles.
+pub fn part_1(tiles: &[(usize,usize)]) -> u128 {
+    let mut max_area = 0u128;
+    for i in 0..tiles.len() {
+        for j in i+1..tiles.len() {
+            let area = ( tiles[i].0.abs_diff(tiles[j].0) as u128 + 1 ) *
+                       ( tiles[i].1.abs_diff(tiles[j].1) as u128 + 1 );
+            max_area = max_area.max(area);
+        }
+    }
+    max_area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{example, solve, Part, SolveError};
+
+    #[test]
+    fn test_parse_dummy_file() {
+        let result = parse("7,1\n11,2").unwrap();
+        
+        assert_eq!(result.len(), 2);
+        assert_eq!(result,vec![(7,1),(11,2)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("7,1\n11").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse("7,1\n11,y").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "y"));
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let example = example!(1);
+        let expected = parse(example).unwrap();
+        assert_eq!(parse(&format!("{}\n", example)).unwrap(), expected);
+        assert_eq!(parse(&format!("{}\r\n", example.replace('\n', "\r\n"))).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&[]), 0);
+        assert_eq!(part_1(&[(7, 1)]), 0);
+        assert_eq!(part_1(&Day09::parse(example!(1)).unwrap()), 50);
+        assert_eq!(part_1(&[(0, 0), (usize::MAX, 1)]), (usize::MAX as u128 + 1) * 2);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let err = solve::<Day09>(example!(1), &[Part::Two], &Params::default()).unwrap_err();
+        assert_eq!(err, SolveError::Part(Part::Two, "part 2 is not solved yet".to_string()));
+    }
+
+    aoc_common::answer_tests!(Day09);
+}
@@ -0,0 +1,53 @@
+//! Day XX
+
+use aoc_common::{non_blank_lines, Answer, Params, ParseError, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(lines: &Vec<String>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(lines).into())
+    }
+
+    fn part_2(lines: &Vec<String>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(lines).into())
+    }
+}
+
+/// Parses one entry per non-blank line.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(non_blank_lines(input)
+        .into_iter()
+        .map(|(_, s)| s.to_string())
+        .collect())
+}
+
+pub fn part_1(_lines: &[String]) -> i32 {
+    0
+}
+
+pub fn part_2(_lines: &[String]) -> i32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty_file() {
+        let result = parse("").unwrap();
+        
+        assert_eq!(result.len(), 0);
+    }
+
+    aoc_common::answer_tests!(DayXX);
+}
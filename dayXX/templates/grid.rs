@@ -0,0 +1,66 @@
+//! Day XX
+
+use aoc_common::{lines, Answer, Params, ParseError, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(grid: &Vec<Vec<char>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(grid).into())
+    }
+
+    fn part_2(grid: &Vec<Vec<char>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(grid).into())
+    }
+}
+
+/// Parses a grid of characters whose rows all have the same width.
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let lines = lines(input);
+    let width = lines.first().map_or(0, |line| line.chars().count());
+    lines.into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if line.chars().count() != width {
+                return Err(ParseError::new(i, 1, line, format!("expected a row of {} cells", width)));
+            }
+            Ok(line.chars().collect())
+        })
+        .collect()
+}
+
+pub fn part_1(_grid: &[Vec<char>]) -> i32 {
+    0
+}
+
+pub fn part_2(_grid: &[Vec<char>]) -> i32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty_file() {
+        let result = parse("").unwrap();
+        
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("..#\n.#").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    aoc_common::answer_tests!(DayXX);
+}
@@ -0,0 +1,59 @@
+//! Day XX
+
+use aoc_common::{non_blank_lines, parse_int_fields, Answer, Params, ParseError, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(rows: &Vec<Vec<i64>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(rows).into())
+    }
+
+    fn part_2(rows: &Vec<Vec<i64>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(rows).into())
+    }
+}
+
+/// Parses the comma separated numbers of every non-blank line.
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    non_blank_lines(input)
+        .into_iter()
+        .map(|(i, line)| parse_int_fields(i, line, ','))
+        .collect()
+}
+
+pub fn part_1(_rows: &[Vec<i64>]) -> i64 {
+    0
+}
+
+pub fn part_2(_rows: &[Vec<i64>]) -> i64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty_file() {
+        let result = parse("").unwrap();
+        
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("1,2\n3,x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+    }
+
+    aoc_common::answer_tests!(DayXX);
+}
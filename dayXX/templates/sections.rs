@@ -0,0 +1,53 @@
+//! Day XX
+
+use aoc_common::{sections, Answer, Params, ParseError, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    type Input = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(sections: &Vec<Vec<String>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_1(sections).into())
+    }
+
+    fn part_2(sections: &Vec<Vec<String>>, _: &Params) -> Result<Answer, String> {
+        Ok(part_2(sections).into())
+    }
+}
+
+/// Parses the sections of the input, which are separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+    Ok(sections(input)
+        .into_iter()
+        .map(|section| section.into_iter().map(|(_, s)| s.to_string()).collect())
+        .collect())
+}
+
+pub fn part_1(_sections: &[Vec<String>]) -> i32 {
+    0
+}
+
+pub fn part_2(_sections: &[Vec<String>]) -> i32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty_file() {
+        let result = parse("").unwrap();
+        
+        assert_eq!(result.len(), 0);
+    }
+
+    aoc_common::answer_tests!(DayXX);
+}
@@ -0,0 +1,11 @@
+//! Compiles and tests the templates `aoc new --template` picks from, which are
+//! not part of any crate otherwise; `lines` is the crate's own `src/lib.rs`.
+
+#[path = "../templates/grid.rs"]
+mod grid;
+
+#[path = "../templates/numbers.rs"]
+mod numbers;
+
+#[path = "../templates/sections.rs"]
+mod sections;